@@ -5,12 +5,13 @@
 
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
 mod operations;
+mod state;
 
 use async_graphql::{Request, Response};
+use ed25519_dalek::{Signature, VerifyingKey};
 use linera_sdk::{
-    linera_base_types::{ContractAbi, ServiceAbi, WithContractAbi, AccountOwner},
+    linera_base_types::{AccountOwner, ContractAbi, ServiceAbi, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
@@ -82,8 +83,8 @@ impl Contract for ThresholdMultisigContract {
         if params.threshold == 0 || params.threshold > params.owners.len() as u64 {
             panic!("Invalid threshold");
         }
-        if params.aggregate_public_key.len() != 32 {
-            panic!("Invalid aggregate public key length");
+        if let Err(error) = parse_aggregate_key(&params.aggregate_public_key) {
+            panic!("{}", error);
         }
 
         // Create initial state
@@ -96,8 +97,8 @@ impl Contract for ThresholdMultisigContract {
                 to,
                 amount,
                 nonce,
-                threshold_signature: _,
-                message: _,
+                threshold_signature,
+                message,
             } => {
                 // Verify nonce
                 if nonce != self.state.nonce() {
@@ -107,9 +108,27 @@ impl Contract for ThresholdMultisigContract {
                     };
                 }
 
-                // NOTE: Threshold signature verification is omitted for now
-                // to be able to compile. In production, this is CRITICAL.
-                // Will be added when ed25519-dalek works correctly in Wasm.
+                // The signed message is rebuilt from the operation, never taken from the caller
+                let expected = ThresholdMessage::transfer(
+                    self.runtime.chain_id(),
+                    self.runtime.application_id().forget_abi(),
+                    nonce,
+                    &to,
+                    amount,
+                );
+                if message != expected.to_bytes() {
+                    return MultisigResponse {
+                        success: false,
+                        message: "Message does not match the operation".to_string(),
+                    };
+                }
+                if let Err(error) = self.verify_threshold_signature(&expected, &threshold_signature)
+                {
+                    return MultisigResponse {
+                        success: false,
+                        message: error,
+                    };
+                }
 
                 // Increment nonce
                 self.state.increment_nonce();
@@ -125,7 +144,7 @@ impl Contract for ThresholdMultisigContract {
                 new_threshold,
                 new_aggregate_key,
                 nonce,
-                threshold_signature: _,
+                threshold_signature,
             } => {
                 // Verify nonce
                 if nonce != self.state.nonce() {
@@ -135,7 +154,40 @@ impl Contract for ThresholdMultisigContract {
                     };
                 }
 
-                // NOTE: Threshold signature verification is omitted for now
+                // Signed by the current aggregate key, covering the new key as well
+                let owners = new_owners.iter().collect::<Vec<_>>();
+                let expected = ThresholdMessage::config_change(
+                    self.runtime.chain_id(),
+                    self.runtime.application_id().forget_abi(),
+                    nonce,
+                    &owners,
+                    new_threshold,
+                    &new_aggregate_key,
+                );
+                if let Err(error) = self.verify_threshold_signature(&expected, &threshold_signature)
+                {
+                    return MultisigResponse {
+                        success: false,
+                        message: error,
+                    };
+                }
+
+                // Validate the new configuration like at instantiation
+                if new_owners.is_empty()
+                    || new_threshold == 0
+                    || new_threshold > new_owners.len() as u64
+                {
+                    return MultisigResponse {
+                        success: false,
+                        message: "Invalid threshold".to_string(),
+                    };
+                }
+                if let Err(error) = parse_aggregate_key(&new_aggregate_key) {
+                    return MultisigResponse {
+                        success: false,
+                        message: error,
+                    };
+                }
 
                 // Update configuration
                 self.state.update_config(new_owners, new_threshold, new_aggregate_key);
@@ -156,3 +208,260 @@ impl Contract for ThresholdMultisigContract {
         self.state.save().await.expect("Failed to save state");
     }
 }
+
+impl ThresholdMultisigContract {
+    /// Verify an Ed25519 signature by the aggregate public key over a threshold message
+    ///
+    /// FROST(Ed25519, SHA-512) signatures verify as plain Ed25519 signatures. Strict
+    /// verification rejects malleable signatures and weak keys.
+    fn verify_threshold_signature(
+        &self,
+        message: &ThresholdMessage,
+        threshold_signature: &[u8],
+    ) -> Result<(), String> {
+        let public_key = parse_aggregate_key(&self.state.aggregate_public_key())?;
+        let signature = Signature::from_slice(threshold_signature)
+            .map_err(|_| "Invalid threshold signature length".to_string())?;
+        public_key
+            .verify_strict(&message.to_bytes(), &signature)
+            .map_err(|_| "Invalid threshold signature".to_string())
+    }
+}
+
+/// Parse a 32-byte compressed Ed25519 aggregate public key
+fn parse_aggregate_key(bytes: &[u8]) -> Result<VerifyingKey, String> {
+    let bytes: &[u8; 32] = bytes
+        .try_into()
+        .map_err(|_| "Invalid aggregate public key length".to_string())?;
+    VerifyingKey::from_bytes(bytes).map_err(|_| "Invalid aggregate public key".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey};
+    use linera_sdk::{
+        linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash},
+        util::BlockingWait,
+        Contract, ContractRuntime,
+    };
+
+    use super::{
+        InitParameters, InstantiationArgument, MultisigOperation, ThresholdMessage,
+        ThresholdMultisigAbi, ThresholdMultisigContract,
+    };
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn chain_id() -> ChainId {
+        ChainId(CryptoHash::test_hash("chain"))
+    }
+
+    fn application_id() -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash("threshold multisig"))
+    }
+
+    fn owner(index: u8) -> AccountOwner {
+        AccountOwner::Address32(CryptoHash::test_hash(format!("owner {index}")))
+    }
+
+    /// 2-of-3 multisig whose aggregate key is `signing_key()`
+    fn setup() -> ThresholdMultisigContract {
+        let runtime = ContractRuntime::new()
+            .with_chain_id(chain_id())
+            .with_application_id(application_id().with_abi::<ThresholdMultisigAbi>())
+            .with_application_parameters(InitParameters {
+                owners: (0..3).map(owner).collect(),
+                threshold: 2,
+                aggregate_public_key: signing_key().verifying_key().to_bytes().to_vec(),
+            });
+        let mut contract = ThresholdMultisigContract::load(runtime).blocking_wait();
+        contract
+            .instantiate(InstantiationArgument {})
+            .blocking_wait();
+        contract
+    }
+
+    fn transfer_message(nonce: u64, amount: u64) -> ThresholdMessage {
+        ThresholdMessage::transfer(chain_id(), application_id(), nonce, &owner(1), amount)
+    }
+
+    fn sign(message: &ThresholdMessage) -> Vec<u8> {
+        signing_key().sign(&message.to_bytes()).to_bytes().to_vec()
+    }
+
+    fn transfer(nonce: u64, amount: u64, threshold_signature: Vec<u8>) -> MultisigOperation {
+        MultisigOperation::ExecuteWithThresholdSignature {
+            to: owner(1),
+            amount,
+            nonce,
+            threshold_signature,
+            message: transfer_message(nonce, amount).to_bytes(),
+        }
+    }
+
+    fn new_signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[9; 32])
+    }
+
+    fn config_message(nonce: u64) -> ThresholdMessage {
+        let owners = (0..4).map(owner).collect::<Vec<_>>();
+        ThresholdMessage::config_change(
+            chain_id(),
+            application_id(),
+            nonce,
+            &owners.iter().collect::<Vec<_>>(),
+            3,
+            &new_signing_key().verifying_key().to_bytes(),
+        )
+    }
+
+    fn change_config(nonce: u64, threshold_signature: Vec<u8>) -> MultisigOperation {
+        MultisigOperation::ChangeConfig {
+            new_owners: (0..4).map(owner).collect(),
+            new_threshold: 3,
+            new_aggregate_key: new_signing_key().verifying_key().to_bytes().to_vec(),
+            nonce,
+            threshold_signature,
+        }
+    }
+
+    #[test]
+    fn test_valid_signature_is_accepted() {
+        let mut contract = setup();
+        let signature = sign(&transfer_message(0, 100));
+
+        let response = contract
+            .execute_operation(transfer(0, 100, signature))
+            .blocking_wait();
+
+        assert!(response.success, "{}", response.message);
+        assert_eq!(contract.state.nonce(), 1);
+    }
+
+    #[test]
+    fn test_signature_over_other_message_is_rejected() {
+        let mut contract = setup();
+        let signature = sign(&transfer_message(0, 100));
+
+        let response = contract
+            .execute_operation(transfer(0, 200, signature))
+            .blocking_wait();
+
+        assert!(!response.success);
+        assert_eq!(response.message, "Invalid threshold signature");
+        assert_eq!(contract.state.nonce(), 0);
+    }
+
+    #[test]
+    fn test_tampered_signature_is_rejected() {
+        let mut contract = setup();
+        let mut signature = sign(&transfer_message(0, 100));
+        signature[0] ^= 1;
+
+        let response = contract
+            .execute_operation(transfer(0, 100, signature))
+            .blocking_wait();
+
+        assert!(!response.success);
+        assert_eq!(response.message, "Invalid threshold signature");
+        assert_eq!(contract.state.nonce(), 0);
+    }
+
+    #[test]
+    fn test_signature_for_other_application_is_rejected() {
+        let mut contract = setup();
+        let other = ThresholdMessage::transfer(
+            chain_id(),
+            ApplicationId::new(CryptoHash::test_hash("other multisig")),
+            0,
+            &owner(1),
+            100,
+        );
+
+        let response = contract
+            .execute_operation(transfer(0, 100, sign(&other)))
+            .blocking_wait();
+
+        assert!(!response.success);
+        assert_eq!(response.message, "Invalid threshold signature");
+        assert_eq!(contract.state.nonce(), 0);
+    }
+
+    #[test]
+    fn test_replayed_nonce_is_rejected() {
+        let mut contract = setup();
+        let signature = sign(&transfer_message(0, 100));
+        let first = contract
+            .execute_operation(transfer(0, 100, signature.clone()))
+            .blocking_wait();
+        assert!(first.success, "{}", first.message);
+
+        let replay = contract
+            .execute_operation(transfer(0, 100, signature))
+            .blocking_wait();
+
+        assert!(!replay.success);
+        assert_eq!(replay.message, "Invalid nonce: expected 1, got 0");
+        assert_eq!(contract.state.nonce(), 1);
+    }
+
+    #[test]
+    fn test_valid_config_change_is_applied() {
+        let mut contract = setup();
+        let signature = sign(&config_message(0));
+
+        let response = contract
+            .execute_operation(change_config(0, signature))
+            .blocking_wait();
+
+        assert!(response.success, "{}", response.message);
+        assert_eq!(contract.state.nonce(), 1);
+        assert_eq!(contract.state.threshold(), 3);
+        assert!(contract.state.is_owner(&owner(3)));
+        assert_eq!(
+            contract.state.aggregate_public_key(),
+            new_signing_key().verifying_key().to_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_tampered_config_change_signature_is_rejected() {
+        let mut contract = setup();
+        let mut signature = sign(&config_message(0));
+        signature[0] ^= 1;
+
+        let response = contract
+            .execute_operation(change_config(0, signature))
+            .blocking_wait();
+
+        assert!(!response.success);
+        assert_eq!(response.message, "Invalid threshold signature");
+        assert_eq!(contract.state.nonce(), 0);
+        assert_eq!(contract.state.threshold(), 2);
+        assert!(!contract.state.is_owner(&owner(3)));
+    }
+
+    #[test]
+    fn test_config_change_signed_by_other_key_is_rejected() {
+        let mut contract = setup();
+        // Signed by the proposed key rather than the current one
+        let signature = new_signing_key()
+            .sign(&config_message(0).to_bytes())
+            .to_bytes()
+            .to_vec();
+
+        let response = contract
+            .execute_operation(change_config(0, signature))
+            .blocking_wait();
+
+        assert!(!response.success);
+        assert_eq!(response.message, "Invalid threshold signature");
+        assert_eq!(contract.state.nonce(), 0);
+        assert_eq!(
+            contract.state.aggregate_public_key(),
+            signing_key().verifying_key().to_bytes().to_vec()
+        );
+    }
+}
@@ -1,8 +1,8 @@
 // Simplified operations to avoid opcode 252
 // Only one main operation: ExecuteWithThresholdSignature
 
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId, ChainId};
 use serde::{Deserialize, Serialize};
-use linera_sdk::linera_base_types::AccountOwner;
 
 /// Main contract operation
 /// Instead of Proposal + Approvals, we use threshold signatures
@@ -20,7 +20,7 @@ pub enum MultisigOperation {
         /// Threshold signature (aggregated off-chain)
         /// Contains signatures from m owners grouped together
         threshold_signature: Vec<u8>,
-        /// Message that was signed (for verification)
+        /// Message that was signed; must equal `ThresholdMessage::transfer(..).to_bytes()`
         message: Vec<u8>,
     },
 
@@ -35,14 +35,24 @@ pub enum MultisigOperation {
         new_aggregate_key: Vec<u8>,
         /// Nonce
         nonce: u64,
-        /// Threshold signature of current configuration
+        /// Threshold signature of current configuration over
+        /// `ThresholdMessage::config_change(..).to_bytes()`, including the new aggregate key
         threshold_signature: Vec<u8>,
     },
 }
 
 /// Message that owners sign off-chain
+///
+/// Binding the chain and application means a signature cannot be replayed on another
+/// deployment that shares the aggregate key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThresholdMessage {
+    /// Multisig chain
+    pub chain_id: ChainId,
+
+    /// Multisig application
+    pub application_id: ApplicationId,
+
     /// Current contract nonce
     pub nonce: u64,
 
@@ -55,8 +65,16 @@ pub struct ThresholdMessage {
 
 impl ThresholdMessage {
     /// Create message for transfer
-    pub fn transfer(nonce: u64, to: &AccountOwner, amount: u64) -> Self {
+    pub fn transfer(
+        chain_id: ChainId,
+        application_id: ApplicationId,
+        nonce: u64,
+        to: &AccountOwner,
+        amount: u64,
+    ) -> Self {
         Self {
+            chain_id,
+            application_id,
             nonce,
             operation_type: "transfer".to_string(),
             operation_data: format!("{}:{}", to.to_string(), amount).into_bytes(),
@@ -64,23 +82,40 @@ impl ThresholdMessage {
     }
 
     /// Create message for configuration change
-    pub fn config_change(nonce: u64, owners: &[&AccountOwner], threshold: u64) -> Self {
-        let owners_str = owners.iter()
+    pub fn config_change(
+        chain_id: ChainId,
+        application_id: ApplicationId,
+        nonce: u64,
+        owners: &[&AccountOwner],
+        threshold: u64,
+        aggregate_key: &[u8],
+    ) -> Self {
+        let owners_str = owners
+            .iter()
             .map(|o| o.to_string())
             .collect::<Vec<_>>()
             .join(",");
+        let key_hex = aggregate_key
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
 
         Self {
+            chain_id,
+            application_id,
             nonce,
             operation_type: "config_change".to_string(),
-            operation_data: format!("{}:{}", owners_str, threshold).into_bytes(),
+            operation_data: format!("{}:{}:{}", owners_str, threshold, key_hex).into_bytes(),
         }
     }
 
     /// Serialize message for signing
     pub fn to_bytes(&self) -> Vec<u8> {
         // Simple serialization to avoid complex operations
+        // Chain and application IDs render as fixed-length hex hashes
         let mut bytes = Vec::new();
+        bytes.extend_from_slice(self.chain_id.to_string().as_bytes());
+        bytes.extend_from_slice(self.application_id.to_string().as_bytes());
         bytes.extend_from_slice(&self.nonce.to_be_bytes());
         bytes.extend_from_slice(self.operation_type.as_bytes());
         bytes.extend_from_slice(&(self.operation_data.len() as u64).to_be_bytes());
@@ -5,21 +5,33 @@
 
 mod state;
 
+use std::collections::{btree_map::Entry, BTreeMap};
+
 use linera_sdk::{
-    linera_base_types::{AccountOwner, Amount, WithContractAbi},
+    abis::fungible::{self, FungibleOperation, FungibleTokenAbi},
+    linera_base_types::{
+        Account, AccountOwner, AccountSignature, Amount, ApplicationId, ChainId, CryptoHash,
+        WithContractAbi,
+    },
     views::{RootView, View},
     Contract, ContractRuntime,
 };
 use log::{info, warn};
 
-use linera_multisig::{MultisigAbi, MultisigOperation, MultisigResponse, ProposalType};
+use linera_multisig::{
+    Allowance, BouncedTransfer, GuardAbi, GuardOperation, GuardResponse, MultisigAbi,
+    MultisigError, MultisigEvent, MultisigMessage, MultisigOperation, MultisigParameters,
+    MultisigResponse, PendingProposalPolicy, ProposalApproval, ProposalContent, ProposalKind,
+    ProposalType, RawApplicationAbi, Role, TransferTier, Veto, MAX_ALLOWANCE_PERIOD,
+    MAX_PROPOSAL_LIFETIME, MAX_TOTAL_WEIGHT, MULTISIG_STREAM_NAME,
+};
 
 use self::state::{MultisigState, Proposal};
 
 /// Multisig contract implementation
 pub struct MultisigContract {
-    state: MultisigState,
-    runtime: ContractRuntime<Self>,
+    pub(crate) state: MultisigState,
+    pub(crate) runtime: ContractRuntime<Self>,
 }
 
 // Required macro export for Wasm compilation
@@ -30,10 +42,10 @@ impl WithContractAbi for MultisigContract {
 }
 
 impl Contract for MultisigContract {
-    type Message = ();
+    type Message = MultisigMessage;
     type InstantiationArgument = InstantiationArgs;
-    type Parameters = ();
-    type EventValue = ();
+    type Parameters = MultisigParameters;
+    type EventValue = MultisigEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = MultisigState::load(runtime.root_view_storage_context())
@@ -49,12 +61,35 @@ impl Contract for MultisigContract {
         // Initialize owners
         self.state.owners.set(args.owners.clone());
 
+        // Initialize weights (default: 1 per owner)
+        if let Some(weights) = &args.weights {
+            if weights.len() != args.owners.len() {
+                panic!("Weights must match the number of owners");
+            }
+            if weights.contains(&0) {
+                panic!("Owner weight must be greater than 0");
+            }
+            let total_weight = weights
+                .iter()
+                .try_fold(0u64, |total, weight| total.checked_add(*weight));
+            if total_weight.is_none_or(|total_weight| total_weight > MAX_TOTAL_WEIGHT) {
+                panic!("Total owner weight cannot exceed {}", MAX_TOTAL_WEIGHT);
+            }
+            let weights = args
+                .owners
+                .iter()
+                .copied()
+                .zip(weights.iter().copied())
+                .collect();
+            self.state.weights.set(weights);
+        }
+
         // Validate and initialize threshold
         if args.threshold == 0 {
             panic!("Threshold must be greater than 0");
         }
-        if args.threshold as usize > args.owners.len() {
-            panic!("Threshold cannot exceed number of owners");
+        if args.threshold > self.state.total_weight() {
+            panic!("Threshold cannot exceed total owner weight");
         }
         self.state.threshold.set(args.threshold);
 
@@ -63,26 +98,79 @@ impl Contract for MultisigContract {
 
         // Set proposal lifetime (default: 7 days = 604800 seconds) - Safe standard
         let lifetime = args.proposal_lifetime.unwrap_or(604800);
+        if let Err(error) = self.ensure_valid_proposal_lifetime(lifetime) {
+            panic!("{}", error);
+        }
         self.state.proposal_lifetime.set(lifetime);
 
         // Set time-delay (default: 0 = disabled, Safe native behavior)
         let delay = args.time_delay.unwrap_or(0);
+        if let Err(error) = self.ensure_valid_time_delay(delay) {
+            panic!("{}", error);
+        }
         self.state.time_delay.set(delay);
 
+        // Initialize transfer tiers (default: none, the Transfer threshold applies)
+        let tiers = args.transfer_tiers.unwrap_or_default();
+        if let Err(error) = self.ensure_valid_transfer_tiers(&tiers) {
+            panic!("{}", error);
+        }
+        self.state.transfer_tiers.set(tiers);
+
+        // Set veto policy (default: no vetoes)
+        let veto_threshold = args.veto_threshold.unwrap_or(0);
+        if veto_threshold > args.owners.len() as u64 {
+            panic!("Veto threshold cannot exceed the number of owners");
+        }
+        self.state.veto_threshold.set(veto_threshold);
+        self.state.guardian.set(args.guardian);
+
+        // Set pending proposal policy (default: keep, previous behavior)
+        let policy = args.pending_proposal_policy.unwrap_or_default();
+        self.state.pending_proposal_policy.set(policy);
+        self.state.config_version.set(0);
+
         info!(
-            "Multisig instantiated: {} owners, threshold={}, lifetime={}s, delay={}s",
+            "Multisig instantiated: {} owners, threshold={}, lifetime={}s, delay={}s, policy={:?}",
             args.owners.len(),
             args.threshold,
             lifetime,
-            delay
+            delay,
+            policy
         );
     }
 
-    async fn execute_operation(&mut self, operation: MultisigOperation) -> MultisigResponse {
+    async fn execute_operation(
+        &mut self,
+        operation: MultisigOperation,
+    ) -> Result<MultisigResponse, MultisigError> {
+        // Modules are identified by the calling application rather than the block signer
+        if let MultisigOperation::ExecuteFromModule { action } = operation {
+            let module = self
+                .runtime
+                .authenticated_caller_id()
+                .ok_or(MultisigError::NotCalledByApplication)?;
+            if self.runtime.chain_id() != self.runtime.application_creator_chain_id() {
+                return Err(MultisigError::NotOnMultisigChain);
+            }
+            return self.execute_from_module(module, action).await;
+        }
+
         let caller = self
             .runtime
             .authenticated_signer()
-            .expect("Operation must be authenticated");
+            .ok_or(MultisigError::NotAuthenticated)?;
+
+        // Deposits move funds straight to the treasury, from any chain
+        if let MultisigOperation::Deposit { amount } = operation {
+            return self.deposit(caller, amount);
+        }
+
+        // Owners running their own chains relay requests to the multisig chain
+        let multisig_chain = self.runtime.application_creator_chain_id();
+        if self.runtime.chain_id() != multisig_chain {
+            return self.forward_to_multisig_chain(multisig_chain, operation);
+        }
 
         match operation {
             MultisigOperation::SubmitProposal { proposal_type } => {
@@ -93,6 +181,11 @@ impl Contract for MultisigContract {
                 self.confirm_proposal(caller, proposal_id).await
             }
 
+            MultisigOperation::ConfirmWithSignatures {
+                proposal_id,
+                signatures,
+            } => self.confirm_with_signatures(proposal_id, signatures).await,
+
             MultisigOperation::ExecuteProposal { proposal_id } => {
                 self.execute_proposal(caller, proposal_id).await
             }
@@ -100,11 +193,126 @@ impl Contract for MultisigContract {
             MultisigOperation::RevokeConfirmation { proposal_id } => {
                 self.revoke_confirmation(caller, proposal_id).await
             }
+
+            MultisigOperation::CancelProposal { proposal_id } => {
+                self.cancel_proposal(caller, proposal_id).await
+            }
+
+            MultisigOperation::RejectProposal { proposal_id } => {
+                self.reject_proposal(caller, proposal_id).await
+            }
+
+            MultisigOperation::VetoProposal {
+                proposal_id,
+                reason,
+            } => self.veto_proposal(caller, proposal_id, reason).await,
+
+            MultisigOperation::SweepExpired => self.sweep_expired().await,
+
+            MultisigOperation::SpendAllowance { to, value } => {
+                self.spend_allowance(caller, to, value).await
+            }
+
+            MultisigOperation::Deposit { .. } | MultisigOperation::ExecuteFromModule { .. } => {
+                unreachable!("Deposits and module actions are handled above")
+            }
         }
     }
 
-    async fn execute_message(&mut self, _message: ()) {
-        panic!("Multisig application doesn't support cross-chain messages yet");
+    async fn execute_message(&mut self, message: MultisigMessage) {
+        // Tracked requests come back here if the multisig chain rejected them
+        if self.runtime.message_is_bouncing() == Some(true) {
+            if let MultisigMessage::TransferReceipt {
+                proposal_id,
+                to,
+                value,
+            } = message
+            {
+                self.record_bounced_transfer(proposal_id, to, value);
+            } else {
+                warn!("Request rejected by the multisig chain: {:?}", message);
+            }
+            return;
+        }
+
+        if let MultisigMessage::TransferReceipt {
+            proposal_id, value, ..
+        } = message
+        {
+            info!(
+                "Received {} tokens from multisig proposal {}",
+                value, proposal_id
+            );
+            return;
+        }
+
+        if self.runtime.chain_id() != self.runtime.application_creator_chain_id() {
+            panic!("Multisig requests must be sent to the multisig chain");
+        }
+
+        // The origin signer is authenticated by the sending chain
+        let caller = self
+            .runtime
+            .authenticated_signer()
+            .expect("Message must be authenticated");
+
+        info!(
+            "Received request from {:?} on chain {:?}",
+            caller,
+            self.runtime.message_origin_chain_id()
+        );
+
+        let response = match message {
+            MultisigMessage::SubmitProposal { proposal_type } => {
+                self.submit_proposal(caller, proposal_type).await
+            }
+
+            MultisigMessage::ConfirmProposal { proposal_id } => {
+                self.confirm_proposal(caller, proposal_id).await
+            }
+
+            MultisigMessage::ConfirmWithSignatures {
+                proposal_id,
+                signatures,
+            } => self.confirm_with_signatures(proposal_id, signatures).await,
+
+            MultisigMessage::ExecuteProposal { proposal_id } => {
+                self.execute_proposal(caller, proposal_id).await
+            }
+
+            MultisigMessage::RevokeConfirmation { proposal_id } => {
+                self.revoke_confirmation(caller, proposal_id).await
+            }
+
+            MultisigMessage::CancelProposal { proposal_id } => {
+                self.cancel_proposal(caller, proposal_id).await
+            }
+
+            MultisigMessage::RejectProposal { proposal_id } => {
+                self.reject_proposal(caller, proposal_id).await
+            }
+
+            MultisigMessage::VetoProposal {
+                proposal_id,
+                reason,
+            } => self.veto_proposal(caller, proposal_id, reason).await,
+
+            MultisigMessage::SweepExpired => self.sweep_expired().await,
+
+            MultisigMessage::SpendAllowance { to, value } => {
+                self.spend_allowance(caller, to, value).await
+            }
+
+            MultisigMessage::TransferReceipt { .. } => {
+                unreachable!("Transfer receipts are handled above")
+            }
+        };
+
+        // Failing requests abort so that they bounce back to the requesting chain
+        match response {
+            Ok(response) => info!("Cross-chain request handled: {:?}", response),
+            Err(error) => panic!("{}", error),
+        }
     }
 
     async fn store(mut self) {
@@ -116,17 +324,83 @@ impl Contract for MultisigContract {
 }
 
 impl MultisigContract {
+    /// Forward an operation to the multisig chain on behalf of the block signer
+    fn forward_to_multisig_chain(
+        &mut self,
+        chain_id: ChainId,
+        operation: MultisigOperation,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let message = MultisigMessage::try_from(operation)?;
+        self.runtime
+            .prepare_message(message)
+            .with_authentication()
+            .with_tracking()
+            .send_to(chain_id);
+
+        info!("Request forwarded to multisig chain {:?}", chain_id);
+
+        Ok(MultisigResponse::RequestForwarded { chain_id })
+    }
+
+    /// Move funds from the signer's account on this chain to the treasury
+    fn deposit(
+        &mut self,
+        depositor: AccountOwner,
+        amount: Amount,
+    ) -> Result<MultisigResponse, MultisigError> {
+        if amount == Amount::ZERO {
+            return Err(MultisigError::ZeroAmount);
+        }
+
+        let treasury = Account::new(
+            self.runtime.application_creator_chain_id(),
+            self.treasury_owner(),
+        );
+        self.runtime.transfer(depositor, treasury, amount);
+        self.emit(MultisigEvent::Deposited { depositor, amount });
+
+        info!(
+            "{:?} deposited {} tokens into the treasury",
+            depositor, amount
+        );
+
+        Ok(MultisigResponse::Deposited { depositor, amount })
+    }
+
+    /// Record a cross-chain transfer that was rejected by its destination chain
+    ///
+    /// Every rejection gets its own entry, so several transfers of one batch can be told apart.
+    fn record_bounced_transfer(&mut self, proposal_id: u64, to: Account, value: Amount) {
+        self.state.bounced_transfers.push(BouncedTransfer {
+            proposal_id,
+            to,
+            value,
+            bounced_at: self.runtime.system_time().micros(),
+        });
+
+        warn!(
+            "Transfer of {} tokens to {:?} from proposal {} was rejected by the destination chain",
+            value, to, proposal_id
+        );
+    }
+
     /// Submit a new proposal (transfer, governance, etc.)
     async fn submit_proposal(
         &mut self,
         caller: AccountOwner,
         proposal_type: ProposalType,
-    ) -> MultisigResponse {
-        // Verify caller is an owner
-        self.ensure_is_owner(&caller);
+    ) -> Result<MultisigResponse, MultisigError> {
+        // Verify caller may propose this type of proposal
+        let may_propose = self.has_role(&caller, Role::Proposer).await
+            || (proposal_type.is_transfer()
+                && self.has_role(&caller, Role::TransferProposer).await);
+        if !may_propose {
+            self.ensure_is_owner(&caller)?;
+            return Err(MultisigError::NotAllowedToPropose(caller));
+        }
 
         // Validate proposal
-        self.validate_proposal(&proposal_type).await;
+        self.validate_proposal(&proposal_type).await?;
 
         // Get current nonce and increment
         let proposal_id = *self.state.nonce.get();
@@ -137,18 +411,33 @@ impl MultisigContract {
 
         // Calculate expiration (Safe standard: 7+ days)
         let lifetime_seconds = *self.state.proposal_lifetime.get();
-        let expires_at = created_at + (lifetime_seconds * 1_000_000);
+        let expires_at = micros_after(created_at, lifetime_seconds);
 
         // Create proposal (executable_after set to 0 initially, updated when threshold reached)
+        let config_version = *self.state.config_version.get();
+        let digest = self.proposal_digest(proposal_id, &proposal_type, config_version);
         let proposal = Proposal {
             id: proposal_id,
             proposal_type,
             proposer: caller,
-            confirmation_count: 0,
+            confirmers: Vec::new(),
+            rejections: Vec::new(),
+            revoked_approvals: Vec::new(),
+            vetoes: Vec::new(),
             executed: false,
+            cancelled: false,
             created_at,
             expires_at,
             executable_after: 0, // Will be set when threshold reached (if time_delay > 0)
+            config_version,
+            digest,
+            response: None,
+        };
+
+        let event = MultisigEvent::ProposalSubmitted {
+            proposal_id,
+            proposer: caller,
+            proposal_type: proposal.proposal_type.clone(),
         };
 
         // Store proposal
@@ -156,282 +445,1393 @@ impl MultisigContract {
             .pending_proposals
             .insert(&proposal_id, proposal)
             .expect("Failed to store proposal");
+        self.emit(event);
 
-        // Auto-confirm from submitter
-        self.confirm_proposal_internal(caller, proposal_id).await;
+        // Auto-confirm from submitter (only owners with the confirmer role)
+        if self.state.owners.get().contains(&caller)
+            && self.has_role(&caller, Role::Confirmer).await
+        {
+            self.confirm_proposal_internal(caller, proposal_id).await?;
+        }
 
-        info!(
-            "Proposal {} submitted by {:?}",
-            proposal_id, caller
-        );
+        info!("Proposal {} submitted by {:?}", proposal_id, caller);
 
-        MultisigResponse::ProposalSubmitted { proposal_id }
+        Ok(MultisigResponse::ProposalSubmitted { proposal_id })
     }
 
     /// Validate a proposal before submission
-    async fn validate_proposal(&self, proposal_type: &ProposalType) {
+    async fn validate_proposal(
+        &mut self,
+        proposal_type: &ProposalType,
+    ) -> Result<(), MultisigError> {
+        match proposal_type {
+            ProposalType::Batch(steps) => {
+                if steps.is_empty() {
+                    return Err(MultisigError::EmptyBatch);
+                }
+                // Each step is validated against the state left by the steps before it
+                let mut snapshot = self.batch_snapshot();
+                let result = self.validate_batch_steps(steps, &mut snapshot).await;
+                self.restore_batch_snapshot(snapshot);
+                result
+            }
+            step => self.validate_step(step).await,
+        }
+    }
+
+    /// Validate the steps of a batch, applying the effects of each one before the next
+    async fn validate_batch_steps(
+        &mut self,
+        steps: &[ProposalType],
+        snapshot: &mut BatchSnapshot,
+    ) -> Result<(), MultisigError> {
+        for step in steps {
+            self.validate_step(step).await?;
+            self.simulate_step(step, snapshot).await;
+        }
+        Ok(())
+    }
+
+    /// Save the settings that validating a batch changes
+    fn batch_snapshot(&self) -> BatchSnapshot {
+        BatchSnapshot {
+            owners: self.state.owners.get().clone(),
+            weights: self.state.weights.get().clone(),
+            threshold: *self.state.threshold.get(),
+            kind_thresholds: self.state.kind_thresholds.get().clone(),
+            veto_threshold: *self.state.veto_threshold.get(),
+            guardian: *self.state.guardian.get(),
+            enabled_modules: self.state.enabled_modules.get().clone(),
+            transfer_tiers: self.state.transfer_tiers.get().clone(),
+            proposal_lifetime: *self.state.proposal_lifetime.get(),
+            time_delay: *self.state.time_delay.get(),
+            kind_time_delays: self.state.kind_time_delays.get().clone(),
+            roles: BTreeMap::new(),
+            allowances: BTreeMap::new(),
+        }
+    }
+
+    /// Undo the effects applied while validating a batch
+    fn restore_batch_snapshot(&mut self, snapshot: BatchSnapshot) {
+        self.state.owners.set(snapshot.owners);
+        self.state.weights.set(snapshot.weights);
+        self.state.threshold.set(snapshot.threshold);
+        self.state.kind_thresholds.set(snapshot.kind_thresholds);
+        self.state.veto_threshold.set(snapshot.veto_threshold);
+        self.state.guardian.set(snapshot.guardian);
+        self.state.enabled_modules.set(snapshot.enabled_modules);
+        self.state.transfer_tiers.set(snapshot.transfer_tiers);
+        self.state.proposal_lifetime.set(snapshot.proposal_lifetime);
+        self.state.time_delay.set(snapshot.time_delay);
+        self.state.kind_time_delays.set(snapshot.kind_time_delays);
+        for (account, roles) in snapshot.roles {
+            self.store_roles(account, roles);
+        }
+        for (delegate, allowance) in snapshot.allowances {
+            self.store_allowance(delegate, allowance);
+        }
+    }
+
+    /// Apply the effects of a validated batch step that later steps are validated against
+    ///
+    /// Only settings are changed: nothing is transferred, called or emitted.
+    async fn simulate_step(&mut self, proposal_type: &ProposalType, snapshot: &mut BatchSnapshot) {
+        match proposal_type {
+            ProposalType::AddOwner { owner } => self.state.owners.get_mut().push(*owner),
+            ProposalType::RemoveOwner { owner } => {
+                self.state
+                    .owners
+                    .get_mut()
+                    .retain(|existing| existing != owner);
+                self.state.weights.get_mut().remove(owner);
+                self.simulate_roles(*owner, None, snapshot).await;
+                self.simulate_allowance(*owner, None, snapshot).await;
+            }
+            ProposalType::ReplaceOwner {
+                old_owner,
+                new_owner,
+            } => {
+                for existing in self.state.owners.get_mut().iter_mut() {
+                    if existing == old_owner {
+                        *existing = *new_owner;
+                    }
+                }
+                let weights = self.state.weights.get_mut();
+                if let Some(weight) = weights.remove(old_owner) {
+                    weights.insert(*new_owner, weight);
+                }
+                let roles = self
+                    .state
+                    .roles
+                    .get(old_owner)
+                    .await
+                    .expect("Failed to read roles");
+                self.simulate_roles(*old_owner, None, snapshot).await;
+                self.simulate_roles(*new_owner, roles, snapshot).await;
+                let allowance = self
+                    .state
+                    .allowances
+                    .get(old_owner)
+                    .await
+                    .expect("Failed to read allowances")
+                    .map(|allowance| Allowance {
+                        delegate: *new_owner,
+                        ..allowance
+                    });
+                self.simulate_allowance(*old_owner, None, snapshot).await;
+                self.simulate_allowance(*new_owner, allowance, snapshot)
+                    .await;
+            }
+            ProposalType::ChangeThreshold { threshold } => self.state.threshold.set(*threshold),
+            ProposalType::SetOwnerWeight { owner, weight } => {
+                self.state.weights.get_mut().insert(*owner, *weight);
+            }
+            ProposalType::SetKindThreshold { kind, threshold } => {
+                let kind_thresholds = self.state.kind_thresholds.get_mut();
+                match threshold {
+                    Some(threshold) => kind_thresholds.insert(*kind, *threshold),
+                    None => kind_thresholds.remove(kind),
+                };
+            }
+            ProposalType::ChangeProposalLifetime { lifetime } => {
+                self.state.proposal_lifetime.set(*lifetime);
+            }
+            ProposalType::ChangeTimeDelay { time_delay } => self.state.time_delay.set(*time_delay),
+            ProposalType::SetKindTimeDelay { kind, time_delay } => {
+                let kind_time_delays = self.state.kind_time_delays.get_mut();
+                match time_delay {
+                    Some(time_delay) => kind_time_delays.insert(*kind, *time_delay),
+                    None => kind_time_delays.remove(kind),
+                };
+            }
+            ProposalType::SetVetoPolicy {
+                veto_threshold,
+                guardian,
+            } => {
+                self.state.veto_threshold.set(*veto_threshold);
+                self.state.guardian.set(*guardian);
+            }
+            ProposalType::SetTransferTiers { tiers } => {
+                self.state.transfer_tiers.set(tiers.clone())
+            }
+            ProposalType::EnableModule { module } => {
+                self.state.enabled_modules.get_mut().push(*module);
+            }
+            ProposalType::DisableModule { module } => {
+                self.state
+                    .enabled_modules
+                    .get_mut()
+                    .retain(|enabled| enabled != module);
+            }
+            ProposalType::SetAllowance {
+                delegate,
+                amount,
+                period,
+            } => {
+                let allowance = Allowance {
+                    delegate: *delegate,
+                    amount: *amount,
+                    period: *period,
+                    spent: Amount::ZERO,
+                    period_start: 0,
+                };
+                self.simulate_allowance(*delegate, Some(allowance), snapshot)
+                    .await;
+            }
+            ProposalType::RemoveAllowance { delegate } => {
+                self.simulate_allowance(*delegate, None, snapshot).await;
+            }
+            ProposalType::GrantRole { account, role } => {
+                let mut roles = self.roles_of(account).await;
+                roles.push(*role);
+                self.simulate_roles(*account, Some(roles), snapshot).await;
+            }
+            ProposalType::RevokeRole { account, role } => {
+                let mut roles = self.roles_of(account).await;
+                roles.retain(|held| held != role);
+                self.simulate_roles(*account, Some(roles), snapshot).await;
+            }
+            _ => {}
+        }
+    }
+
+    /// Change the explicit roles of an account during batch validation, saving the original
+    async fn simulate_roles(
+        &mut self,
+        account: AccountOwner,
+        roles: Option<Vec<Role>>,
+        snapshot: &mut BatchSnapshot,
+    ) {
+        if let Entry::Vacant(entry) = snapshot.roles.entry(account) {
+            let original = self
+                .state
+                .roles
+                .get(&account)
+                .await
+                .expect("Failed to read roles");
+            entry.insert(original);
+        }
+        self.store_roles(account, roles);
+    }
+
+    /// Change the allowance of a delegate during batch validation, saving the original
+    async fn simulate_allowance(
+        &mut self,
+        delegate: AccountOwner,
+        allowance: Option<Allowance>,
+        snapshot: &mut BatchSnapshot,
+    ) {
+        if let Entry::Vacant(entry) = snapshot.allowances.entry(delegate) {
+            let original = self
+                .state
+                .allowances
+                .get(&delegate)
+                .await
+                .expect("Failed to read allowances");
+            entry.insert(original);
+        }
+        self.store_allowance(delegate, allowance);
+    }
+
+    /// Set (or clear, with `None`) the allowance of a delegate
+    fn store_allowance(&mut self, delegate: AccountOwner, allowance: Option<Allowance>) {
+        match allowance {
+            Some(allowance) => self
+                .state
+                .allowances
+                .insert(&delegate, allowance)
+                .expect("Failed to store allowance"),
+            None => self
+                .state
+                .allowances
+                .remove(&delegate)
+                .expect("Failed to remove allowance"),
+        }
+    }
+
+    /// Set (or clear, with `None`) the explicit roles of an account
+    fn store_roles(&mut self, account: AccountOwner, roles: Option<Vec<Role>>) {
+        match roles {
+            Some(roles) => self
+                .state
+                .roles
+                .insert(&account, roles)
+                .expect("Failed to store roles"),
+            None => self
+                .state
+                .roles
+                .remove(&account)
+                .expect("Failed to remove roles"),
+        }
+    }
+
+    /// Validate a single (non-batch) proposal step
+    async fn validate_step(&mut self, proposal_type: &ProposalType) -> Result<(), MultisigError> {
         match proposal_type {
-            ProposalType::Transfer { value, .. } => {
+            ProposalType::Batch(_) => {
+                return Err(MultisigError::NestedBatch);
+            }
+            ProposalType::LegacyTransfer { value, .. } => {
                 if *value == 0 {
-                    panic!("Transfer amount must be greater than 0");
+                    return Err(MultisigError::ZeroAmount);
+                }
+            }
+            ProposalType::Transfer { value, .. }
+            | ProposalType::CrossChainTransfer { value, .. } => {
+                if *value == Amount::ZERO {
+                    return Err(MultisigError::ZeroAmount);
+                }
+            }
+            ProposalType::TokenTransfer { token, value, .. } => {
+                if *value == Amount::ZERO {
+                    return Err(MultisigError::ZeroAmount);
+                }
+                if !self
+                    .runtime
+                    .application_parameters()
+                    .allowed_tokens
+                    .contains(token)
+                {
+                    return Err(MultisigError::TokenNotAllowed(*token));
                 }
             }
             ProposalType::AddOwner { owner } => {
                 let owners = self.state.owners.get();
                 if owners.contains(owner) {
-                    panic!("Owner already exists");
+                    return Err(MultisigError::OwnerAlreadyExists(*owner));
                 }
+                self.total_weight_with(owner, 1)?;
             }
             ProposalType::RemoveOwner { owner } => {
                 let owners = self.state.owners.get();
                 if !owners.contains(owner) {
-                    panic!("Owner does not exist");
+                    return Err(MultisigError::OwnerNotFound(*owner));
                 }
-                let threshold = *self.state.threshold.get();
-                // Ensure we don't go below threshold after removal
-                if owners.len() - 1 < threshold as usize {
-                    panic!("Cannot remove owner: would make threshold impossible to reach");
+                let threshold = self.state.max_threshold();
+                // Ensure the remaining weight can still reach every threshold
+                if self.state.total_weight() - self.state.weight_of(owner) < threshold {
+                    return Err(MultisigError::ThresholdUnreachable);
                 }
+                // Ensure the remaining owners can still reach the veto threshold
+                self.ensure_valid_veto_threshold_for(owners.len() as u64 - 1)?;
             }
             ProposalType::ReplaceOwner { old_owner, new_owner } => {
                 let owners = self.state.owners.get();
                 if !owners.contains(old_owner) {
-                    panic!("Old owner does not exist");
+                    return Err(MultisigError::OwnerNotFound(*old_owner));
                 }
                 if owners.contains(new_owner) {
-                    panic!("New owner already exists");
+                    return Err(MultisigError::OwnerAlreadyExists(*new_owner));
                 }
             }
             ProposalType::ChangeThreshold { threshold } => {
-                if *threshold == 0 {
-                    panic!("Threshold cannot be zero");
+                self.ensure_valid_threshold(*threshold)?;
+            }
+            ProposalType::SetOwnerWeight { owner, weight } => {
+                if !self.state.owners.get().contains(owner) {
+                    return Err(MultisigError::OwnerNotFound(*owner));
                 }
-                let owners = self.state.owners.get();
-                if *threshold as usize > owners.len() {
-                    panic!("Threshold cannot exceed number of owners");
+                if *weight == 0 {
+                    return Err(MultisigError::ZeroWeight);
+                }
+                let total_weight = self.total_weight_with(owner, *weight)?;
+                if total_weight < self.state.max_threshold() {
+                    return Err(MultisigError::ThresholdUnreachable);
+                }
+            }
+            ProposalType::SetKindThreshold { threshold, .. } => {
+                if let Some(threshold) = threshold {
+                    self.ensure_valid_threshold(*threshold)?;
+                }
+            }
+            ProposalType::ChangeProposalLifetime { lifetime } => {
+                self.ensure_valid_proposal_lifetime(*lifetime)?;
+            }
+            ProposalType::ChangeTimeDelay { time_delay } => {
+                self.ensure_valid_time_delay(*time_delay)?;
+            }
+            ProposalType::SetKindTimeDelay { time_delay, .. } => {
+                if let Some(time_delay) = time_delay {
+                    self.ensure_valid_time_delay(*time_delay)?;
+                }
+            }
+            ProposalType::SetVetoPolicy { veto_threshold, .. } => {
+                self.ensure_valid_veto_threshold(*veto_threshold)?;
+            }
+            ProposalType::SetTransferTiers { tiers } => {
+                self.ensure_valid_transfer_tiers(tiers)?;
+            }
+            ProposalType::SetGuard { guard } => {
+                if *guard == Some(self.runtime.application_id().forget_abi()) {
+                    return Err(MultisigError::SelfReference);
+                }
+            }
+            ProposalType::EnableModule { module } => {
+                if *module == self.runtime.application_id().forget_abi() {
+                    return Err(MultisigError::SelfReference);
+                }
+                if self.state.enabled_modules.get().contains(module) {
+                    return Err(MultisigError::ModuleAlreadyEnabled(*module));
+                }
+            }
+            ProposalType::DisableModule { module } => {
+                if !self.state.enabled_modules.get().contains(module) {
+                    return Err(MultisigError::ModuleNotEnabled(*module));
                 }
             }
+            ProposalType::SetAllowance { amount, period, .. } => {
+                if *amount == Amount::ZERO {
+                    return Err(MultisigError::ZeroAllowance);
+                }
+                if *period == 0 {
+                    return Err(MultisigError::ZeroAllowancePeriod);
+                }
+                if *period > MAX_ALLOWANCE_PERIOD {
+                    return Err(MultisigError::AllowancePeriodTooLong {
+                        period: *period,
+                        max_period: MAX_ALLOWANCE_PERIOD,
+                    });
+                }
+            }
+            ProposalType::RemoveAllowance { delegate } => {
+                let exists = self
+                    .state
+                    .allowances
+                    .contains_key(delegate)
+                    .await
+                    .expect("Failed to read allowances");
+                if !exists {
+                    return Err(MultisigError::AllowanceNotFound(*delegate));
+                }
+            }
+            ProposalType::GrantRole { account, role } => {
+                if self.roles_of(account).await.contains(role) {
+                    return Err(MultisigError::RoleAlreadyGranted {
+                        account: *account,
+                        role: *role,
+                    });
+                }
+            }
+            ProposalType::RevokeRole { account, role } => {
+                if !self.roles_of(account).await.contains(role) {
+                    return Err(MultisigError::RoleNotGranted {
+                        account: *account,
+                        role: *role,
+                    });
+                }
+            }
+            ProposalType::CallApplication {
+                application_id,
+                operation_bytes,
+            } => {
+                if operation_bytes.is_empty() {
+                    return Err(MultisigError::EmptyApplicationCall);
+                }
+                if *application_id == self.runtime.application_id().forget_abi() {
+                    return Err(MultisigError::SelfReference);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Total owner weight once `owner` holds `weight`, if it stays within [`MAX_TOTAL_WEIGHT`]
+    fn total_weight_with(&self, owner: &AccountOwner, weight: u64) -> Result<u64, MultisigError> {
+        (self.state.total_weight() - self.state.weight_of(owner))
+            .checked_add(weight)
+            .filter(|total_weight| *total_weight <= MAX_TOTAL_WEIGHT)
+            .ok_or(MultisigError::TotalWeightTooHigh {
+                max_total_weight: MAX_TOTAL_WEIGHT,
+            })
+    }
+
+    /// Ensure a threshold is positive and reachable with the current owner weight
+    fn ensure_valid_threshold(&self, threshold: u64) -> Result<(), MultisigError> {
+        if threshold == 0 {
+            return Err(MultisigError::ZeroThreshold);
+        }
+        let total_weight = self.state.total_weight();
+        if threshold > total_weight {
+            return Err(MultisigError::ThresholdAboveTotalWeight {
+                threshold,
+                total_weight,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure a proposal lifetime is positive, bounded and longer than every time-delay
+    fn ensure_valid_proposal_lifetime(&self, lifetime: u64) -> Result<(), MultisigError> {
+        if lifetime == 0 {
+            return Err(MultisigError::ZeroProposalLifetime);
+        }
+        if lifetime > MAX_PROPOSAL_LIFETIME {
+            return Err(MultisigError::ProposalLifetimeTooLong {
+                lifetime,
+                max_lifetime: MAX_PROPOSAL_LIFETIME,
+            });
+        }
+        let max_time_delay = self.state.max_time_delay();
+        if lifetime <= max_time_delay {
+            return Err(MultisigError::ProposalLifetimeTooShort {
+                lifetime,
+                max_time_delay,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure a time-delay is shorter than the proposal lifetime
+    fn ensure_valid_time_delay(&self, time_delay: u64) -> Result<(), MultisigError> {
+        let proposal_lifetime = *self.state.proposal_lifetime.get();
+        if time_delay >= proposal_lifetime {
+            return Err(MultisigError::TimeDelayTooLong {
+                time_delay,
+                proposal_lifetime,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure transfer tiers are well formed and their time-delays stay below the proposal lifetime
+    fn ensure_valid_transfer_tiers(&self, tiers: &[TransferTier]) -> Result<(), MultisigError> {
+        validate_transfer_tiers(tiers, self.state.total_weight())?;
+        for time_delay in tiers.iter().filter_map(|tier| tier.time_delay) {
+            self.ensure_valid_time_delay(time_delay)?;
+        }
+        Ok(())
+    }
+
+    /// Ensure a veto threshold can be reached by the current owners
+    fn ensure_valid_veto_threshold(&self, veto_threshold: u64) -> Result<(), MultisigError> {
+        let owner_count = self.state.owners.get().len() as u64;
+        if veto_threshold > owner_count {
+            return Err(MultisigError::VetoThresholdTooHigh {
+                veto_threshold,
+                owner_count,
+            });
+        }
+        Ok(())
+    }
+
+    /// Ensure the current veto threshold stays reachable with `owner_count` owners
+    fn ensure_valid_veto_threshold_for(&self, owner_count: u64) -> Result<(), MultisigError> {
+        let veto_threshold = *self.state.veto_threshold.get();
+        if veto_threshold > owner_count {
+            return Err(MultisigError::VetoThresholdTooHigh {
+                veto_threshold,
+                owner_count,
+            });
         }
+        Ok(())
     }
 
     /// Confirm a pending proposal
-    async fn confirm_proposal(&mut self, caller: AccountOwner, proposal_id: u64) -> MultisigResponse {
-        self.ensure_is_owner(&caller);
+    async fn confirm_proposal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_is_owner(&caller)?;
+        self.ensure_has_role(&caller, Role::Confirmer).await?;
 
-        let confirmations = self.confirm_proposal_internal(caller, proposal_id).await;
+        let confirmations = self.confirm_proposal_internal(caller, proposal_id).await?;
 
-        MultisigResponse::ProposalConfirmed {
+        Ok(MultisigResponse::ProposalConfirmed {
             proposal_id,
             confirmations,
-        }
+        })
     }
 
-    /// Internal confirmation logic
-    async fn confirm_proposal_internal(&mut self, caller: AccountOwner, proposal_id: u64) -> u64 {
-        let mut proposal = self
-            .state
-            .pending_proposals
-            .get(&proposal_id)
-            .await
-            .expect("Failed to get proposal")
-            .unwrap_or_else(|| panic!("Proposal {} not found", proposal_id));
+    /// Confirm a pending proposal for every owner who signed its approval off-chain
+    ///
+    /// All signatures are checked before any confirmation is recorded, so one bad signature
+    /// rejects the whole request.
+    async fn confirm_with_signatures(
+        &mut self,
+        proposal_id: u64,
+        signatures: Vec<AccountSignature>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        if signatures.is_empty() {
+            return Err(MultisigError::NoSignatures);
+        }
 
-        if proposal.executed {
-            panic!("Proposal already executed");
+        let proposal = self.pending_proposal(proposal_id).await?;
+        let approval = ProposalApproval {
+            digest: proposal.digest,
+        };
+
+        let mut signers = Vec::with_capacity(signatures.len());
+        for signature in &signatures {
+            let signer = signature.owner();
+            signature
+                .verify(&approval)
+                .map_err(|_| MultisigError::InvalidSignature(signer))?;
+            // A signature stays valid after its owner revokes, so it must not count again
+            if proposal.revoked_approvals.contains(&signer) {
+                return Err(MultisigError::ApprovalRevoked {
+                    proposal_id,
+                    owner: signer,
+                });
+            }
+            self.ensure_is_owner(&signer)?;
+            self.ensure_has_role(&signer, Role::Confirmer).await?;
+            signers.push(signer);
+        }
+
+        let mut confirmations = 0;
+        for signer in signers {
+            confirmations = self.confirm_proposal_internal(signer, proposal_id).await?;
+        }
+
+        Ok(MultisigResponse::ProposalConfirmed {
+            proposal_id,
+            confirmations,
+        })
+    }
+
+    /// Canonical digest of a proposal on this multisig under a configuration version
+    fn proposal_digest(
+        &mut self,
+        proposal_id: u64,
+        proposal_type: &ProposalType,
+        config_version: u64,
+    ) -> CryptoHash {
+        ProposalContent {
+            chain_id: self.runtime.chain_id(),
+            application_id: self.runtime.application_id().forget_abi(),
+            config_version,
+            proposal_id,
+            proposal_type: proposal_type.clone(),
         }
+        .digest()
+    }
+
+    /// Internal confirmation logic
+    async fn confirm_proposal_internal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<u64, MultisigError> {
+        let mut proposal = self.pending_proposal(proposal_id).await?;
 
-        // Get existing confirmations for this owner
-        let mut confirmed_proposals = self.state.confirmations.get(&caller).await.unwrap().unwrap_or_default();
+        let owners = self.state.owners.get().clone();
+        let threshold = self.state.required_threshold(&proposal.proposal_type);
 
         // Check if already confirmed
-        if confirmed_proposals.contains(&proposal_id) {
-            warn!("Owner {:?} already confirmed proposal {}", caller, proposal_id);
-            return proposal.confirmation_count;
+        if proposal.confirmers.contains(&caller) {
+            warn!(
+                "Owner {:?} already confirmed proposal {}",
+                caller, proposal_id
+            );
+            return Ok(proposal.confirmation_count(&owners));
         }
 
         // Add confirmation
+        let mut confirmed_proposals = self
+            .state
+            .confirmations
+            .get(&caller)
+            .await
+            .unwrap()
+            .unwrap_or_default();
         confirmed_proposals.push(proposal_id);
-        self.state.confirmations.insert(&caller, confirmed_proposals)
+        self.state
+            .confirmations
+            .insert(&caller, confirmed_proposals)
             .expect("Failed to store confirmations");
+        // A confirmation replaces any previous rejection from the same owner
+        proposal.rejections.retain(|rejecter| rejecter != &caller);
+        let weight_before = self.state.confirmation_weight(&proposal);
+        proposal.confirmers.push(caller);
+        let weight_after = self.state.confirmation_weight(&proposal);
 
-        // Update confirmation count
-        proposal.confirmation_count += 1;
+        self.emit(MultisigEvent::ProposalConfirmed {
+            proposal_id,
+            owner: caller,
+            confirmation_weight: weight_after,
+        });
 
         // Set executable_after when threshold is reached (if time_delay > 0)
-        let threshold = *self.state.threshold.get();
-        let time_delay = *self.state.time_delay.get();
-        if proposal.confirmation_count == threshold && time_delay > 0 {
-            let now = self.runtime.system_time().micros();
-            proposal.executable_after = now + (time_delay * 1_000_000);
-            info!(
-                "Proposal {} reached threshold, executable in {} seconds",
-                proposal_id, time_delay
-            );
+        if weight_before < threshold && weight_after >= threshold {
+            let time_delay = self.state.required_time_delay(&proposal.proposal_type);
+            if time_delay > 0 {
+                let now = self.runtime.system_time().micros();
+                proposal.executable_after = micros_after(now, time_delay);
+                info!(
+                    "Proposal {} reached threshold, executable in {} seconds",
+                    proposal_id, time_delay
+                );
+            }
+            self.emit(MultisigEvent::ThresholdReached {
+                proposal_id,
+                executable_after: proposal.executable_after,
+            });
         }
 
-        let confirmation_count = proposal.confirmation_count;
+        let confirmation_count = proposal.confirmation_count(&owners);
         self.state
             .pending_proposals
             .insert(&proposal_id, proposal)
             .expect("Failed to store proposal");
 
         info!(
-            "Proposal {} confirmed by {:?} (total: {})",
-            proposal_id, caller, confirmation_count
+            "Proposal {} confirmed by {:?} (total: {}, weight: {}/{})",
+            proposal_id, caller, confirmation_count, weight_after, threshold
         );
 
-        confirmation_count
+        Ok(confirmation_count)
     }
 
     /// Execute a confirmed proposal
-    async fn execute_proposal(&mut self, caller: AccountOwner, proposal_id: u64) -> MultisigResponse {
-        self.ensure_is_owner(&caller);
+    async fn execute_proposal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_has_role(&caller, Role::Executor).await?;
 
-        let proposal = self
-            .state
-            .pending_proposals
-            .get(&proposal_id)
-            .await
-            .expect("Failed to get proposal")
-            .unwrap_or_else(|| panic!("Proposal {} not found", proposal_id));
-
-        if proposal.executed {
-            panic!("Proposal already executed");
-        }
+        let proposal = self.pending_proposal(proposal_id).await?;
 
         // Check expiration (Safe standard)
         let now = self.runtime.system_time().micros();
         if now > proposal.expires_at {
-            panic!(
-                "Proposal expired: current time {} > expiration {}",
-                now, proposal.expires_at
-            );
+            return Err(MultisigError::ProposalExpired {
+                now,
+                expires_at: proposal.expires_at,
+            });
         }
 
-        let threshold = *self.state.threshold.get();
+        // Proposals from an older configuration cannot run under the invalidate policy
+        let config_version = *self.state.config_version.get();
+        if *self.state.pending_proposal_policy.get() == PendingProposalPolicy::Invalidate
+            && proposal.config_version != config_version
+        {
+            return Err(MultisigError::StaleConfigurationVersion {
+                proposal_version: proposal.config_version,
+                current_version: config_version,
+            });
+        }
 
-        if proposal.confirmation_count < threshold {
-            panic!(
-                "Insufficient confirmations: {} < {} (required)",
-                proposal.confirmation_count, threshold
-            );
+        // Only confirmations from current owners count toward the threshold of this kind
+        let threshold = self.state.required_threshold(&proposal.proposal_type);
+        let confirmation_weight = self.state.confirmation_weight(&proposal);
+
+        if confirmation_weight < threshold {
+            return Err(MultisigError::InsufficientConfirmations {
+                confirmation_weight,
+                threshold,
+            });
         }
 
-        // Check time-delay if configured (optional feature)
-        let time_delay = *self.state.time_delay.get();
-        if time_delay > 0 && now < proposal.executable_after {
+        // Check the time-delay stamped when the threshold was reached; later changes to the
+        // time-delay settings do not move a running timer
+        if now < proposal.executable_after {
             let wait_seconds = (proposal.executable_after - now) / 1_000_000;
-            panic!(
-                "Time-delay not met: must wait {} more seconds (configure time_delay=0 to disable)",
-                wait_seconds
-            );
+            return Err(MultisigError::TimeDelayNotMet { wait_seconds });
+        }
+
+        // The guard vets every execution, except changes of the guard itself so that a faulty
+        // guard can always be replaced through governance. Once it has been called, failures
+        // abort the transaction so that the guard's own state is reverted as well
+        let guard = match proposal.proposal_type {
+            ProposalType::SetGuard { .. } => None,
+            _ => *self.state.guard.get(),
+        };
+        if let Some(guard) = guard {
+            let operation = GuardOperation::CheckProposal {
+                proposal_id,
+                proposal_type: proposal.proposal_type.clone(),
+                executor: caller,
+            };
+            if let Err(error) = self.check_guard(guard, proposal_id, operation) {
+                panic!("{}", error);
+            }
         }
 
+        // Governance changes report the pending proposals they bring over their threshold
+        let reached_before = if proposal.proposal_type.changes_owners_or_threshold() {
+            self.proposals_meeting_threshold().await
+        } else {
+            Vec::new()
+        };
+
         // Execute based on proposal type
         let response = match &proposal.proposal_type {
-            ProposalType::Transfer { to, value, .. } => {
-                self.execute_transfer(caller, *to, *value).await
-            }
-            ProposalType::AddOwner { owner } => {
-                self.execute_add_owner(*owner).await
-            }
-            ProposalType::RemoveOwner { owner } => {
-                self.execute_remove_owner(*owner).await
-            }
-            ProposalType::ReplaceOwner { old_owner, new_owner } => {
-                self.execute_replace_owner(*old_owner, *new_owner).await
-            }
-            ProposalType::ChangeThreshold { threshold } => {
-                self.execute_change_threshold(*threshold).await
+            // Steps run in order within this transaction; a failing step aborts the whole
+            // batch, reverting the steps that already ran
+            ProposalType::Batch(steps) => {
+                let mut responses = Vec::with_capacity(steps.len());
+                for (index, step) in steps.iter().enumerate() {
+                    match self.execute_step(proposal_id, step).await {
+                        Ok(response) => responses.push(response),
+                        Err(error) => panic!("Batch step {} failed: {}", index, error),
+                    }
+                }
+                MultisigResponse::BatchExecuted { responses }
             }
+            step => match self.execute_step(proposal_id, step).await {
+                Ok(response) => response,
+                Err(error) if guard.is_some() => panic!("{}", error),
+                Err(error) => return Err(error),
+            },
         };
 
+        // A post-execution veto reverts the whole transaction, including the execution
+        if let Some(guard) = guard {
+            let operation = GuardOperation::CheckAfterExecution {
+                proposal_id,
+                proposal_type: proposal.proposal_type.clone(),
+                executor: caller,
+                response: response.clone(),
+            };
+            if let Err(error) = self.check_guard(guard, proposal_id, operation) {
+                panic!("{}", error);
+            }
+        }
+
         // Mark as executed and move to executed proposals
         let mut executed_proposal = proposal.clone();
         executed_proposal.executed = true;
+        executed_proposal.response = Some(response.clone());
         self.state
             .executed_proposals
             .insert(&proposal_id, executed_proposal)
             .expect("Failed to store executed proposal");
-        
+
         // Remove from pending
-        self.state.pending_proposals.remove(&proposal_id)
+        self.state
+            .pending_proposals
+            .remove(&proposal_id)
             .expect("Failed to remove pending proposal");
 
+        self.emit(MultisigEvent::ProposalExecuted {
+            proposal_id,
+            executor: caller,
+            response: response.clone(),
+        });
+
+        if proposal.proposal_type.changes_owners_or_threshold() {
+            self.apply_pending_proposal_policy(&reached_before).await;
+        }
+
+        info!("Proposal {} executed by {:?}", proposal_id, caller);
+
+        Ok(response)
+    }
+
+    /// Call the guard application and report whether it rejects the proposal
+    fn check_guard(
+        &mut self,
+        guard: ApplicationId,
+        proposal_id: u64,
+        operation: GuardOperation,
+    ) -> Result<(), MultisigError> {
+        let response =
+            self.runtime
+                .call_application(true, guard.with_abi::<GuardAbi>(), &operation);
+
+        match response {
+            GuardResponse::Approved => Ok(()),
+            GuardResponse::Rejected { reason } => Err(MultisigError::GuardRejected {
+                proposal_id,
+                reason,
+            }),
+        }
+    }
+
+    /// Load a pending proposal that has not been executed yet
+    async fn pending_proposal(&self, proposal_id: u64) -> Result<Proposal, MultisigError> {
+        let proposal = self
+            .state
+            .pending_proposals
+            .get(&proposal_id)
+            .await
+            .expect("Failed to get proposal")
+            .ok_or(MultisigError::ProposalNotFound(proposal_id))?;
+
+        if proposal.executed {
+            return Err(MultisigError::ProposalAlreadyExecuted(proposal_id));
+        }
+        Ok(proposal)
+    }
+
+    /// IDs of the pending proposals whose confirmations meet their threshold
+    async fn proposals_meeting_threshold(&self) -> Vec<u64> {
+        let pending_ids = self
+            .state
+            .pending_proposals
+            .indices()
+            .await
+            .expect("Failed to read pending proposals");
+
+        let mut reached = Vec::new();
+        for proposal_id in pending_ids {
+            let proposal = self
+                .state
+                .pending_proposals
+                .get(&proposal_id)
+                .await
+                .expect("Failed to get proposal")
+                .expect("Pending proposal disappeared");
+            let threshold = self.state.required_threshold(&proposal.proposal_type);
+            if self.state.confirmation_weight(&proposal) >= threshold {
+                reached.push(proposal_id);
+            }
+        }
+        reached
+    }
+
+    /// Start a new configuration version and apply the pending proposal policy to it
+    ///
+    /// `reached_before` lists the pending proposals that met their threshold under the
+    /// previous configuration; the others emit [`MultisigEvent::ThresholdReached`] if they
+    /// meet it now.
+    async fn apply_pending_proposal_policy(&mut self, reached_before: &[u64]) {
+        let config_version = *self.state.config_version.get() + 1;
+        self.state.config_version.set(config_version);
+
+        let policy = *self.state.pending_proposal_policy.get();
+        let pending_ids = self
+            .state
+            .pending_proposals
+            .indices()
+            .await
+            .expect("Failed to read pending proposals");
+
+        let now = self.runtime.system_time().micros();
+
+        for proposal_id in pending_ids {
+            let mut proposal = self
+                .state
+                .pending_proposals
+                .get(&proposal_id)
+                .await
+                .expect("Failed to get proposal")
+                .expect("Pending proposal disappeared");
+
+            if policy == PendingProposalPolicy::Invalidate {
+                self.cancel_pending_proposal(proposal).await;
+                info!(
+                    "Proposal {} invalidated by configuration change",
+                    proposal_id
+                );
+                continue;
+            }
+
+            // Proposals that meet the new threshold without a running timer start their
+            // time-delay now, so lowering the threshold never skips it; running timers are kept
+            let threshold = self.state.required_threshold(&proposal.proposal_type);
+            let time_delay = self.state.required_time_delay(&proposal.proposal_type);
+            let meets_threshold = self.state.confirmation_weight(&proposal) >= threshold;
+            let starts_timer = meets_threshold && time_delay > 0 && proposal.executable_after == 0;
+            if starts_timer {
+                proposal.executable_after = micros_after(now, time_delay);
+            }
+            if meets_threshold && !reached_before.contains(&proposal_id) {
+                self.emit(MultisigEvent::ThresholdReached {
+                    proposal_id,
+                    executable_after: proposal.executable_after,
+                });
+            }
+
+            if policy == PendingProposalPolicy::Reevaluate {
+                // Proposals that no longer meet the threshold lose their timer
+                if !meets_threshold {
+                    proposal.executable_after = 0;
+                }
+                proposal.config_version = config_version;
+                proposal.digest =
+                    self.proposal_digest(proposal_id, &proposal.proposal_type, config_version);
+            } else if !starts_timer {
+                // Kept proposals are only rewritten when their timer starts
+                continue;
+            }
+            self.state
+                .pending_proposals
+                .insert(&proposal_id, proposal)
+                .expect("Failed to store proposal");
+        }
+
         info!(
-            "Proposal {} executed by {:?}",
-            proposal_id, caller
+            "Configuration version {}: applied {:?} policy to pending proposals",
+            config_version, policy
         );
+    }
 
-        response
+    /// Execute a single (non-batch) proposal step
+    async fn execute_step(
+        &mut self,
+        proposal_id: u64,
+        proposal_type: &ProposalType,
+    ) -> Result<MultisigResponse, MultisigError> {
+        match proposal_type {
+            ProposalType::Batch(_) => Err(MultisigError::NestedBatch),
+            ProposalType::LegacyTransfer { to, value, .. } => {
+                self.execute_transfer(*to, Amount::from_tokens((*value).into()))
+                    .await
+            }
+            ProposalType::Transfer { to, value, .. } => self.execute_transfer(*to, *value).await,
+            ProposalType::CrossChainTransfer { to, value, .. } => {
+                self.execute_cross_chain_transfer(proposal_id, *to, *value)
+                    .await
+            }
+            ProposalType::TokenTransfer { token, to, value } => {
+                self.execute_token_transfer(*token, *to, *value).await
+            }
+            ProposalType::AddOwner { owner } => {
+                self.execute_add_owner(*owner).await
+            }
+            ProposalType::RemoveOwner { owner } => {
+                self.execute_remove_owner(*owner).await
+            }
+            ProposalType::ReplaceOwner { old_owner, new_owner } => {
+                self.execute_replace_owner(*old_owner, *new_owner).await
+            }
+            ProposalType::ChangeThreshold { threshold } => {
+                self.execute_change_threshold(*threshold).await
+            }
+            ProposalType::SetOwnerWeight { owner, weight } => {
+                self.execute_set_owner_weight(*owner, *weight).await
+            }
+            ProposalType::SetKindThreshold { kind, threshold } => {
+                self.execute_set_kind_threshold(*kind, *threshold).await
+            }
+            ProposalType::ChangeProposalLifetime { lifetime } => {
+                self.execute_change_proposal_lifetime(*lifetime).await
+            }
+            ProposalType::ChangeTimeDelay { time_delay } => {
+                self.execute_change_time_delay(*time_delay).await
+            }
+            ProposalType::SetKindTimeDelay { kind, time_delay } => {
+                self.execute_set_kind_time_delay(*kind, *time_delay).await
+            }
+            ProposalType::SetVetoPolicy {
+                veto_threshold,
+                guardian,
+            } => {
+                self.execute_set_veto_policy(*veto_threshold, *guardian)
+                    .await
+            }
+            ProposalType::SetTransferTiers { tiers } => {
+                self.execute_set_transfer_tiers(tiers.clone()).await
+            }
+            ProposalType::SetGuard { guard } => self.execute_set_guard(*guard).await,
+            ProposalType::EnableModule { module } => self.execute_enable_module(*module).await,
+            ProposalType::DisableModule { module } => self.execute_disable_module(*module).await,
+            ProposalType::CallApplication {
+                application_id,
+                operation_bytes,
+            } => {
+                self.execute_call_application(*application_id, operation_bytes.clone())
+                    .await
+            }
+            ProposalType::SetAllowance {
+                delegate,
+                amount,
+                period,
+            } => {
+                self.execute_set_allowance(*delegate, *amount, *period)
+                    .await
+            }
+            ProposalType::RemoveAllowance { delegate } => {
+                self.execute_remove_allowance(*delegate).await
+            }
+            ProposalType::GrantRole { account, role } => {
+                self.execute_grant_role(*account, *role).await
+            }
+            ProposalType::RevokeRole { account, role } => {
+                self.execute_revoke_role(*account, *role).await
+            }
+        }
     }
 
     /// Execute a transfer
-    async fn execute_transfer(&mut self, source: AccountOwner, to: AccountOwner, value: u64) -> MultisigResponse {
-        // Convert u64 to Amount (from_tokens expects u128)
-        let amount = Amount::from_tokens(value.into());
+    async fn execute_transfer(
+        &mut self,
+        to: AccountOwner,
+        value: Amount,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let chain_id = self.runtime.chain_id();
+        self.transfer_funds(Account::new(chain_id, to), value)?;
+
+        info!("Transferred {} tokens to {:?}", value, to);
+
+        Ok(MultisigResponse::FundsTransferred { to, value })
+    }
+
+    /// Execute a transfer to an account that may live on another chain
+    async fn execute_cross_chain_transfer(
+        &mut self,
+        proposal_id: u64,
+        to: Account,
+        value: Amount,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.transfer_funds(to, value)?;
+
+        // Track the transfer so that a rejection can be traced back to this proposal
+        if to.chain_id != self.runtime.chain_id() {
+            self.runtime
+                .prepare_message(MultisigMessage::TransferReceipt {
+                    proposal_id,
+                    to,
+                    value,
+                })
+                .with_tracking()
+                .send_to(to.chain_id);
+        }
+
+        info!("Transferred {} tokens to {:?}", value, to);
+
+        Ok(MultisigResponse::FundsTransferredToAccount { to, value })
+    }
+
+    /// Account owner of the multisig application, which holds the treasury
+    fn treasury_owner(&mut self) -> AccountOwner {
+        AccountOwner::from(self.runtime.application_id().forget_abi())
+    }
+
+    /// Move treasury funds to a destination account, checking the balance before and after
+    fn transfer_funds(&mut self, destination: Account, value: Amount) -> Result<(), MultisigError> {
+        let treasury = self.treasury_owner();
 
         // Validate balance before transfer (prevent state corruption)
-        let contract_balance = self.runtime.chain_balance();
-        if contract_balance < amount {
-            panic!(
-                "Insufficient balance: required={}, available={}",
-                amount, contract_balance
-            );
+        let treasury_balance = self.runtime.owner_balance(treasury);
+        if treasury_balance < value {
+            return Err(MultisigError::InsufficientBalance {
+                required: value,
+                available: treasury_balance,
+            });
         }
 
-        // Execute the actual transfer from contract to destination
-        let chain_id = self.runtime.chain_id();
-        let destination = linera_sdk::linera_base_types::Account::new(chain_id, to);
-        self.runtime.transfer(source, destination, amount);
+        // Execute the actual transfer from the treasury to the destination
+        self.runtime.transfer(treasury, destination, value);
+        self.emit(MultisigEvent::FundsTransferred {
+            to: destination,
+            value,
+        });
 
         // Validate post-transfer balance (ensure transfer succeeded)
-        let new_balance = self.runtime.chain_balance();
-        if new_balance >= contract_balance {
+        let new_balance = self.runtime.owner_balance(treasury);
+        if new_balance >= treasury_balance {
             panic!("Transfer validation failed - balance did not decrease");
         }
+        Ok(())
+    }
 
-        info!("Transferred {} tokens to {:?}", value, to);
+    /// Transfer fungible tokens owned by the multisig application account
+    async fn execute_token_transfer(
+        &mut self,
+        token: ApplicationId,
+        to: Account,
+        value: Amount,
+    ) -> Result<MultisigResponse, MultisigError> {
+        // The token application authenticates the multisig as the calling application
+        let operation = FungibleOperation::Transfer {
+            owner: self.treasury_owner(),
+            amount: value,
+            target_account: fungible::Account {
+                chain_id: to.chain_id,
+                owner: to.owner,
+            },
+        };
+        self.runtime
+            .call_application(true, token.with_abi::<FungibleTokenAbi>(), &operation);
+        self.emit(MultisigEvent::TokensTransferred { token, to, value });
+
+        info!("Transferred {} tokens of {} to {:?}", value, token, to);
+
+        Ok(MultisigResponse::TokensTransferred { token, to, value })
+    }
+
+    /// Execute an application call with the multisig as the authenticated caller
+    async fn execute_call_application(
+        &mut self,
+        application_id: ApplicationId,
+        operation_bytes: Vec<u8>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let response = self.runtime.call_application(
+            true,
+            application_id.with_abi::<RawApplicationAbi>(),
+            &operation_bytes,
+        );
+
+        info!(
+            "Called application {:?} ({} bytes returned)",
+            application_id,
+            response.len()
+        );
+
+        Ok(MultisigResponse::ApplicationCalled {
+            application_id,
+            response,
+        })
+    }
+
+    /// Execute set allowance (starts a fresh period)
+    async fn execute_set_allowance(
+        &mut self,
+        delegate: AccountOwner,
+        amount: Amount,
+        period: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let allowance = Allowance {
+            delegate,
+            amount,
+            period,
+            spent: Amount::ZERO,
+            period_start: self.runtime.system_time().micros(),
+        };
+        self.state
+            .allowances
+            .insert(&delegate, allowance)
+            .expect("Failed to store allowance");
+
+        info!(
+            "Allowance of {} tokens every {}s granted to {:?}",
+            amount, period, delegate
+        );
+
+        Ok(MultisigResponse::AllowanceSet {
+            delegate,
+            amount,
+            period,
+        })
+    }
+
+    /// Execute remove allowance
+    async fn execute_remove_allowance(
+        &mut self,
+        delegate: AccountOwner,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.state
+            .allowances
+            .remove(&delegate)
+            .expect("Failed to remove allowance");
+
+        info!("Allowance of {:?} removed", delegate);
+
+        Ok(MultisigResponse::AllowanceRemoved { delegate })
+    }
+
+    /// Spend from an allowance without going through a proposal
+    async fn spend_allowance(
+        &mut self,
+        caller: AccountOwner,
+        to: Account,
+        value: Amount,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let mut allowance = self
+            .state
+            .allowances
+            .get(&caller)
+            .await
+            .expect("Failed to get allowance")
+            .ok_or(MultisigError::AllowanceNotFound(caller))?;
+
+        if value == Amount::ZERO {
+            return Err(MultisigError::ZeroAmount);
+        }
+
+        // Reset the spent amount when the current period has elapsed
+        let now = self.runtime.system_time().micros();
+        let period_micros = allowance.period.saturating_mul(1_000_000).max(1);
+        if now >= allowance.period_start.saturating_add(period_micros) {
+            let elapsed_periods = (now - allowance.period_start) / period_micros;
+            allowance.period_start += elapsed_periods * period_micros;
+            allowance.spent = Amount::ZERO;
+        }
+
+        let remaining = allowance.amount.saturating_sub(allowance.spent);
+        if value > remaining {
+            return Err(MultisigError::AllowanceExceeded {
+                requested: value,
+                remaining,
+            });
+        }
+
+        self.transfer_funds(to, value)?;
 
-        MultisigResponse::FundsTransferred { to, value }
+        allowance.spent = allowance.spent.saturating_add(value);
+        let remaining = allowance.amount.saturating_sub(allowance.spent);
+        self.state
+            .allowances
+            .insert(&caller, allowance)
+            .expect("Failed to store allowance");
+
+        info!(
+            "{:?} spent {} tokens from allowance to {:?} ({} remaining)",
+            caller, value, to, remaining
+        );
+
+        Ok(MultisigResponse::AllowanceSpent {
+            delegate: caller,
+            to,
+            value,
+            remaining,
+        })
+    }
+
+    /// Execute grant role (owners without explicit roles start from every role)
+    async fn execute_grant_role(
+        &mut self,
+        account: AccountOwner,
+        role: Role,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let mut roles = self.roles_of(&account).await;
+
+        if roles.contains(&role) {
+            return Err(MultisigError::RoleAlreadyGranted { account, role });
+        }
+
+        roles.push(role);
+        self.state
+            .roles
+            .insert(&account, roles)
+            .expect("Failed to store roles");
+
+        info!("Role {:?} granted to {:?}", role, account);
+
+        Ok(MultisigResponse::RoleGranted { account, role })
+    }
+
+    /// Execute revoke role
+    async fn execute_revoke_role(
+        &mut self,
+        account: AccountOwner,
+        role: Role,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let mut roles = self.roles_of(&account).await;
+
+        if !roles.contains(&role) {
+            return Err(MultisigError::RoleNotGranted { account, role });
+        }
+
+        roles.retain(|held| held != &role);
+        self.state
+            .roles
+            .insert(&account, roles)
+            .expect("Failed to store roles");
+
+        info!("Role {:?} revoked from {:?}", role, account);
+
+        Ok(MultisigResponse::RoleRevoked { account, role })
     }
 
     /// Execute add owner
-    async fn execute_add_owner(&mut self, owner: AccountOwner) -> MultisigResponse {
+    async fn execute_add_owner(
+        &mut self,
+        owner: AccountOwner,
+    ) -> Result<MultisigResponse, MultisigError> {
         let mut owners = self.state.owners.get().clone();
-        
+
         if owners.contains(&owner) {
-            panic!("Owner already exists");
+            return Err(MultisigError::OwnerAlreadyExists(owner));
         }
-        
+        self.total_weight_with(&owner, 1)?;
+
         owners.push(owner);
         self.state.owners.set(owners);
-        
+        self.emit(MultisigEvent::OwnerAdded { owner });
+
         info!("Owner {:?} added", owner);
-        
-        MultisigResponse::OwnerAdded { owner }
+
+        Ok(MultisigResponse::OwnerAdded { owner })
     }
 
     /// Execute remove owner
-    async fn execute_remove_owner(&mut self, owner: AccountOwner) -> MultisigResponse {
+    async fn execute_remove_owner(
+        &mut self,
+        owner: AccountOwner,
+    ) -> Result<MultisigResponse, MultisigError> {
         let mut owners = self.state.owners.get().clone();
-        
+
         if let Some(pos) = owners.iter().position(|o| o == &owner) {
             owners.remove(pos);
-            
-            // Ensure we don't go below threshold
-            let threshold = *self.state.threshold.get();
-            if owners.len() < threshold as usize {
-                panic!("Cannot remove owner: would go below threshold");
+
+            // Ensure we don't go below any threshold
+            let threshold = self.state.max_threshold();
+            if self.state.total_weight() - self.state.weight_of(&owner) < threshold {
+                return Err(MultisigError::ThresholdUnreachable);
             }
-            
+            self.ensure_valid_veto_threshold_for(owners.len() as u64)?;
+
             self.state.owners.set(owners);
+            self.state.weights.get_mut().remove(&owner);
+            // Explicit roles and allowances do not outlive ownership
+            self.state
+                .roles
+                .remove(&owner)
+                .expect("Failed to remove roles");
+            self.store_allowance(owner, None);
+            self.forget_owner(owner).await;
+            self.emit(MultisigEvent::OwnerRemoved { owner });
             
             info!("Owner {:?} removed", owner);
             
-            MultisigResponse::OwnerRemoved { owner }
+            Ok(MultisigResponse::OwnerRemoved { owner })
         } else {
-            panic!("Owner not found");
+            Err(MultisigError::OwnerNotFound(owner))
         }
     }
 
@@ -440,103 +1840,856 @@ impl MultisigContract {
         &mut self,
         old_owner: AccountOwner,
         new_owner: AccountOwner,
-    ) -> MultisigResponse {
+    ) -> Result<MultisigResponse, MultisigError> {
         let mut owners = self.state.owners.get().clone();
-        
+
         if let Some(pos) = owners.iter().position(|o| o == &old_owner) {
             if owners.contains(&new_owner) {
-                panic!("New owner already exists");
+                return Err(MultisigError::OwnerAlreadyExists(new_owner));
             }
-            
+
             owners[pos] = new_owner;
             self.state.owners.set(owners);
-            
+
+            // The new owner inherits the weight of the old one
+            let weights = self.state.weights.get_mut();
+            if let Some(weight) = weights.remove(&old_owner) {
+                weights.insert(new_owner, weight);
+            }
+
+            // ... and its roles, so a rotation never widens the permissions of the seat
+            let roles = self
+                .state
+                .roles
+                .get(&old_owner)
+                .await
+                .expect("Failed to read roles");
+            match roles {
+                Some(roles) => self
+                    .state
+                    .roles
+                    .insert(&new_owner, roles)
+                    .expect("Failed to store roles"),
+                None => self
+                    .state
+                    .roles
+                    .remove(&new_owner)
+                    .expect("Failed to remove roles"),
+            }
+            self.state
+                .roles
+                .remove(&old_owner)
+                .expect("Failed to remove roles");
+
+            // ... and its allowance, re-addressed to the new owner
+            let allowance = self
+                .state
+                .allowances
+                .get(&old_owner)
+                .await
+                .expect("Failed to read allowances")
+                .map(|allowance| Allowance {
+                    delegate: new_owner,
+                    ..allowance
+                });
+            self.store_allowance(new_owner, allowance);
+            self.store_allowance(old_owner, None);
+            self.forget_owner(old_owner).await;
+            self.emit(MultisigEvent::OwnerReplaced {
+                old_owner,
+                new_owner,
+            });
+
             info!("Owner {:?} replaced with {:?}", old_owner, new_owner);
-            
-            MultisigResponse::OwnerReplaced { old_owner, new_owner }
+
+            Ok(MultisigResponse::OwnerReplaced {
+                old_owner,
+                new_owner,
+            })
         } else {
-            panic!("Old owner not found");
+            Err(MultisigError::OwnerNotFound(old_owner))
         }
     }
 
     /// Execute change threshold
-    async fn execute_change_threshold(&mut self, threshold: u64) -> MultisigResponse {
-        let owners = self.state.owners.get();
-        
-        if threshold == 0 {
-            panic!("Threshold cannot be zero");
-        }
-        
-        if threshold as usize > owners.len() {
-            panic!("Threshold cannot exceed number of owners");
-        }
-        
+    async fn execute_change_threshold(
+        &mut self,
+        threshold: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_valid_threshold(threshold)?;
+
         self.state.threshold.set(threshold);
-        
+        self.emit(MultisigEvent::ThresholdChanged { threshold });
+
         info!("Threshold changed to {}", threshold);
-        
-        MultisigResponse::ThresholdChanged { new_threshold: threshold }
+
+        Ok(MultisigResponse::ThresholdChanged {
+            new_threshold: threshold,
+        })
     }
 
-    /// Revoke a confirmation
-    async fn revoke_confirmation(&mut self, caller: AccountOwner, proposal_id: u64) -> MultisigResponse {
-        self.ensure_is_owner(&caller);
+    /// Execute set kind threshold
+    async fn execute_set_kind_threshold(
+        &mut self,
+        kind: ProposalKind,
+        threshold: Option<u64>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        match threshold {
+            Some(threshold) => {
+                self.ensure_valid_threshold(threshold)?;
+                self.state.kind_thresholds.get_mut().insert(kind, threshold);
+            }
+            None => {
+                self.state.kind_thresholds.get_mut().remove(&kind);
+            }
+        }
 
-        let mut proposal = self
-            .state
-            .pending_proposals
-            .get(&proposal_id)
-            .await
-            .expect("Failed to get proposal")
-            .unwrap_or_else(|| panic!("Proposal {} not found", proposal_id));
+        info!(
+            "Threshold for {:?} proposals changed to {:?}",
+            kind, threshold
+        );
 
-        if proposal.executed {
-            panic!("Cannot revoke confirmation for executed proposal");
+        Ok(MultisigResponse::KindThresholdChanged { kind, threshold })
+    }
+
+    /// Execute enable module
+    async fn execute_enable_module(
+        &mut self,
+        module: ApplicationId,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let modules = self.state.enabled_modules.get_mut();
+        if modules.contains(&module) {
+            return Err(MultisigError::ModuleAlreadyEnabled(module));
         }
+        modules.push(module);
 
-        let mut confirmed_proposals = self.state.confirmations.get(&caller).await.unwrap().unwrap_or_default();
+        info!("Module {} enabled", module);
 
-        if let Some(pos) = confirmed_proposals.iter().position(|&id| id == proposal_id) {
-            confirmed_proposals.remove(pos);
-            self.state.confirmations.insert(&caller, confirmed_proposals)
-                .expect("Failed to store confirmations");
+        Ok(MultisigResponse::ModuleEnabled { module })
+    }
+
+    /// Execute disable module
+    async fn execute_disable_module(
+        &mut self,
+        module: ApplicationId,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let modules = self.state.enabled_modules.get_mut();
+        if !modules.contains(&module) {
+            return Err(MultisigError::ModuleNotEnabled(module));
+        }
+        modules.retain(|enabled| *enabled != module);
+
+        info!("Module {} disabled", module);
+
+        Ok(MultisigResponse::ModuleDisabled { module })
+    }
+
+    /// Perform an action for an enabled module and record it as an executed proposal
+    async fn execute_from_module(
+        &mut self,
+        module: ApplicationId,
+        action: ProposalType,
+    ) -> Result<MultisigResponse, MultisigError> {
+        if !self.state.enabled_modules.get().contains(&module) {
+            return Err(MultisigError::ModuleNotEnabled(module));
+        }
+        match action {
+            ProposalType::LegacyTransfer { .. }
+            | ProposalType::Transfer { .. }
+            | ProposalType::CrossChainTransfer { .. }
+            | ProposalType::TokenTransfer { .. }
+            | ProposalType::CallApplication { .. } => {}
+            _ => return Err(MultisigError::UnsupportedModuleAction),
+        }
+        self.validate_proposal(&action).await?;
+
+        // The proposal ID is only consumed once the action succeeds
+        let proposal_id = *self.state.nonce.get();
+        let executor = AccountOwner::from(module);
+
+        // Module actions are vetted by the guard like any proposal execution, and failures
+        // after the guard was called abort the transaction
+        let guard = *self.state.guard.get();
+        if let Some(guard) = guard {
+            let operation = GuardOperation::CheckProposal {
+                proposal_id,
+                proposal_type: action.clone(),
+                executor,
+            };
+            if let Err(error) = self.check_guard(guard, proposal_id, operation) {
+                panic!("{}", error);
+            }
+        }
+
+        let response = match self.execute_step(proposal_id, &action).await {
+            Ok(response) => response,
+            Err(error) if guard.is_some() => panic!("{}", error),
+            Err(error) => return Err(error),
+        };
+
+        if let Some(guard) = guard {
+            let operation = GuardOperation::CheckAfterExecution {
+                proposal_id,
+                proposal_type: action.clone(),
+                executor,
+                response: response.clone(),
+            };
+            if let Err(error) = self.check_guard(guard, proposal_id, operation) {
+                panic!("{}", error);
+            }
+        }
+        self.state.nonce.set(proposal_id + 1);
+
+        // Recorded in the history like an executed proposal with no confirmations
+        let now = self.runtime.system_time().micros();
+        let config_version = *self.state.config_version.get();
+        let digest = self.proposal_digest(proposal_id, &action, config_version);
+        let proposal = Proposal {
+            id: proposal_id,
+            proposal_type: action,
+            proposer: executor,
+            confirmers: Vec::new(),
+            rejections: Vec::new(),
+            revoked_approvals: Vec::new(),
+            vetoes: Vec::new(),
+            executed: true,
+            cancelled: false,
+            created_at: now,
+            expires_at: now,
+            executable_after: now,
+            config_version,
+            digest,
+            response: Some(response.clone()),
+        };
+        self.state
+            .executed_proposals
+            .insert(&proposal_id, proposal)
+            .expect("Failed to store executed proposal");
+
+        self.emit(MultisigEvent::ProposalExecuted {
+            proposal_id,
+            executor,
+            response: response.clone(),
+        });
+
+        info!("Module {} executed action {}", module, proposal_id);
+
+        Ok(MultisigResponse::ModuleActionExecuted {
+            module,
+            proposal_id,
+            response: Box::new(response),
+        })
+    }
+
+    /// Execute set guard
+    async fn execute_set_guard(
+        &mut self,
+        guard: Option<ApplicationId>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.state.guard.set(guard);
+
+        info!("Guard changed to {:?}", guard);
+
+        Ok(MultisigResponse::GuardChanged { guard })
+    }
+
+    /// Execute change proposal lifetime (pending proposals keep their expiration)
+    async fn execute_change_proposal_lifetime(
+        &mut self,
+        lifetime: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_valid_proposal_lifetime(lifetime)?;
+        self.state.proposal_lifetime.set(lifetime);
+
+        info!("Proposal lifetime changed to {} seconds", lifetime);
+
+        Ok(MultisigResponse::ProposalLifetimeChanged { lifetime })
+    }
+
+    /// Execute change time-delay (running timers are not moved)
+    async fn execute_change_time_delay(
+        &mut self,
+        time_delay: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_valid_time_delay(time_delay)?;
+        self.state.time_delay.set(time_delay);
+
+        info!("Time-delay changed to {} seconds", time_delay);
+
+        Ok(MultisigResponse::TimeDelayChanged { time_delay })
+    }
+
+    /// Execute set kind time-delay (running timers are not moved)
+    async fn execute_set_kind_time_delay(
+        &mut self,
+        kind: ProposalKind,
+        time_delay: Option<u64>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        match time_delay {
+            Some(delay) => {
+                self.ensure_valid_time_delay(delay)?;
+                self.state.kind_time_delays.get_mut().insert(kind, delay);
+            }
+            None => {
+                self.state.kind_time_delays.get_mut().remove(&kind);
+            }
+        }
+
+        info!(
+            "Time-delay for {:?} proposals changed to {:?}",
+            kind, time_delay
+        );
+
+        Ok(MultisigResponse::KindTimeDelayChanged { kind, time_delay })
+    }
+
+    /// Execute set veto policy
+    async fn execute_set_veto_policy(
+        &mut self,
+        veto_threshold: u64,
+        guardian: Option<AccountOwner>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_valid_veto_threshold(veto_threshold)?;
+        self.state.veto_threshold.set(veto_threshold);
+        self.state.guardian.set(guardian);
+
+        info!(
+            "Veto policy changed: threshold={}, guardian={:?}",
+            veto_threshold, guardian
+        );
+
+        Ok(MultisigResponse::VetoPolicyChanged {
+            veto_threshold,
+            guardian,
+        })
+    }
+
+    /// Execute set transfer tiers
+    async fn execute_set_transfer_tiers(
+        &mut self,
+        tiers: Vec<TransferTier>,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_valid_transfer_tiers(&tiers)?;
+
+        let tier_count = tiers.len() as u64;
+        self.state.transfer_tiers.set(tiers);
+
+        info!("Transfer tiers replaced: {} tiers", tier_count);
+
+        Ok(MultisigResponse::TransferTiersChanged { tier_count })
+    }
+
+    /// Execute set owner weight
+    async fn execute_set_owner_weight(
+        &mut self,
+        owner: AccountOwner,
+        weight: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        if !self.state.owners.get().contains(&owner) {
+            return Err(MultisigError::OwnerNotFound(owner));
+        }
+
+        if weight == 0 {
+            return Err(MultisigError::ZeroWeight);
+        }
+
+        let total_weight = self.total_weight_with(&owner, weight)?;
+        if total_weight < self.state.max_threshold() {
+            return Err(MultisigError::ThresholdUnreachable);
+        }
+
+        self.state.weights.get_mut().insert(owner, weight);
+        self.emit(MultisigEvent::OwnerWeightChanged { owner, weight });
+
+        info!("Owner {:?} weight changed to {}", owner, weight);
+
+        Ok(MultisigResponse::OwnerWeightChanged { owner, weight })
+    }
+
+    /// Revoke a confirmation
+    async fn revoke_confirmation(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_has_role(&caller, Role::Confirmer).await?;
+
+        let mut proposal = self.pending_proposal(proposal_id).await?;
+
+        if let Some(pos) = proposal
+            .confirmers
+            .iter()
+            .position(|confirmer| confirmer == &caller)
+        {
+            proposal.confirmers.remove(pos);
+            if !proposal.revoked_approvals.contains(&caller) {
+                proposal.revoked_approvals.push(caller);
+            }
+            self.remove_confirmation(caller, proposal_id).await;
 
-            proposal.confirmation_count = proposal.confirmation_count.saturating_sub(1);
             self.state
                 .pending_proposals
                 .insert(&proposal_id, proposal)
                 .expect("Failed to store proposal");
+            self.emit(MultisigEvent::ConfirmationRevoked {
+                proposal_id,
+                owner: caller,
+            });
 
             info!(
                 "Confirmation revoked by {:?} for proposal {}",
                 caller, proposal_id
             );
 
-            MultisigResponse::ConfirmationRevoked { proposal_id }
+            Ok(MultisigResponse::ConfirmationRevoked { proposal_id })
         } else {
             warn!("Owner {:?} has not confirmed proposal {}", caller, proposal_id);
-            MultisigResponse::ConfirmationRevoked { proposal_id }
+            Ok(MultisigResponse::ConfirmationRevoked { proposal_id })
+        }
+    }
+
+    /// Cancel a pending proposal (allowed for the proposer and for cancellers)
+    async fn cancel_proposal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let proposal = self.pending_proposal(proposal_id).await?;
+
+        if proposal.proposer != caller && !self.has_role(&caller, Role::Canceller).await {
+            return Err(MultisigError::NotAllowedToCancel(proposal_id));
+        }
+
+        self.cancel_pending_proposal(proposal).await;
+
+        info!("Proposal {} cancelled by {:?}", proposal_id, caller);
+
+        Ok(MultisigResponse::ProposalCancelled { proposal_id })
+    }
+
+    /// Vote against a pending proposal, cancelling it once the threshold can no longer be reached
+    async fn reject_proposal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+    ) -> Result<MultisigResponse, MultisigError> {
+        self.ensure_is_owner(&caller)?;
+        self.ensure_has_role(&caller, Role::Confirmer).await?;
+
+        let mut proposal = self.pending_proposal(proposal_id).await?;
+
+        if proposal.rejections.contains(&caller) {
+            warn!(
+                "Owner {:?} already rejected proposal {}",
+                caller, proposal_id
+            );
+            return Ok(MultisigResponse::ProposalRejected {
+                proposal_id,
+                rejections: proposal.rejection_count(self.state.owners.get()),
+                cancelled: false,
+            });
+        }
+
+        // A rejection replaces any previous confirmation from the same owner
+        if let Some(pos) = proposal
+            .confirmers
+            .iter()
+            .position(|confirmer| confirmer == &caller)
+        {
+            proposal.confirmers.remove(pos);
+            if !proposal.revoked_approvals.contains(&caller) {
+                proposal.revoked_approvals.push(caller);
+            }
+            self.remove_confirmation(caller, proposal_id).await;
+        }
+
+        proposal.rejections.push(caller);
+        let rejections = proposal.rejection_count(self.state.owners.get());
+        self.emit(MultisigEvent::ProposalRejected {
+            proposal_id,
+            owner: caller,
+        });
+
+        info!(
+            "Proposal {} rejected by {:?} (total: {})",
+            proposal_id, caller, rejections
+        );
+
+        // Cancel once the remaining owner weight can no longer reach the threshold
+        let threshold = self.state.required_threshold(&proposal.proposal_type);
+        let remaining_weight = self.state.total_weight() - self.state.rejection_weight(&proposal);
+        let cancelled = remaining_weight < threshold;
+        if cancelled {
+            self.cancel_pending_proposal(proposal).await;
+            info!(
+                "Proposal {} cancelled: threshold can no longer be reached",
+                proposal_id
+            );
+        } else {
+            self.state
+                .pending_proposals
+                .insert(&proposal_id, proposal)
+                .expect("Failed to store proposal");
+        }
+
+        Ok(MultisigResponse::ProposalRejected {
+            proposal_id,
+            rejections,
+            cancelled,
+        })
+    }
+
+    /// Veto a proposal waiting out its time-delay, cancelling it once the guardian or enough
+    /// owners veto
+    async fn veto_proposal(
+        &mut self,
+        caller: AccountOwner,
+        proposal_id: u64,
+        reason: String,
+    ) -> Result<MultisigResponse, MultisigError> {
+        let is_guardian = *self.state.guardian.get() == Some(caller);
+        if !is_guardian {
+            self.ensure_is_owner(&caller)?;
+            if *self.state.veto_threshold.get() == 0 {
+                return Err(MultisigError::OwnerVetoesDisabled);
+            }
+        }
+
+        let mut proposal = self.pending_proposal(proposal_id).await?;
+
+        // Vetoes are only possible while the time-delay is running
+        let now = self.runtime.system_time().micros();
+        if proposal.executable_after == 0 || now >= proposal.executable_after {
+            return Err(MultisigError::NotInTimeDelay(proposal_id));
+        }
+        if proposal.vetoes.iter().any(|veto| veto.vetoer == caller) {
+            return Err(MultisigError::AlreadyVetoed {
+                vetoer: caller,
+                proposal_id,
+            });
+        }
+
+        proposal.vetoes.push(Veto {
+            vetoer: caller,
+            reason: reason.clone(),
+            vetoed_at: now,
+        });
+        let vetoes = proposal.veto_count(self.state.owners.get());
+        self.emit(MultisigEvent::ProposalVetoed {
+            proposal_id,
+            vetoer: caller,
+            reason: reason.clone(),
+        });
+
+        info!(
+            "Proposal {} vetoed by {:?} (total: {}): {}",
+            proposal_id, caller, vetoes, reason
+        );
+
+        let veto_threshold = *self.state.veto_threshold.get();
+        let cancelled = is_guardian || (veto_threshold > 0 && vetoes >= veto_threshold);
+        if cancelled {
+            self.cancel_pending_proposal(proposal).await;
+            info!("Proposal {} cancelled by veto", proposal_id);
+        } else {
+            self.state
+                .pending_proposals
+                .insert(&proposal_id, proposal)
+                .expect("Failed to store proposal");
+        }
+
+        Ok(MultisigResponse::ProposalVetoed {
+            proposal_id,
+            vetoes,
+            cancelled,
+        })
+    }
+
+    /// Remove a proposal ID from an owner's confirmation list
+    async fn remove_confirmation(&mut self, owner: AccountOwner, proposal_id: u64) {
+        let mut confirmed_proposals = self
+            .state
+            .confirmations
+            .get(&owner)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        confirmed_proposals.retain(|&id| id != proposal_id);
+
+        if confirmed_proposals.is_empty() {
+            self.state
+                .confirmations
+                .remove(&owner)
+                .expect("Failed to remove confirmations");
+        } else {
+            self.state
+                .confirmations
+                .insert(&owner, confirmed_proposals)
+                .expect("Failed to store confirmations");
+        }
+    }
+
+    /// Drop the confirmations of an owner who was removed or replaced
+    async fn forget_owner(&mut self, owner: AccountOwner) {
+        let confirmed_proposals = self
+            .state
+            .confirmations
+            .get(&owner)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+
+        for proposal_id in confirmed_proposals {
+            let proposal = self
+                .state
+                .pending_proposals
+                .get(&proposal_id)
+                .await
+                .expect("Failed to get proposal");
+
+            if let Some(mut proposal) = proposal {
+                proposal.confirmers.retain(|confirmer| confirmer != &owner);
+                self.state
+                    .pending_proposals
+                    .insert(&proposal_id, proposal)
+                    .expect("Failed to store proposal");
+            }
+        }
+
+        self.state
+            .confirmations
+            .remove(&owner)
+            .expect("Failed to remove confirmations");
+    }
+
+    /// Move a pending proposal to the cancelled proposals and drop its confirmations
+    async fn cancel_pending_proposal(&mut self, mut proposal: Proposal) {
+        let proposal_id = proposal.id;
+        proposal.cancelled = true;
+
+        self.state
+            .cancelled_proposals
+            .insert(&proposal_id, proposal)
+            .expect("Failed to store cancelled proposal");
+        self.state
+            .pending_proposals
+            .remove(&proposal_id)
+            .expect("Failed to remove pending proposal");
+        self.emit(MultisigEvent::ProposalCancelled { proposal_id });
+
+        self.purge_confirmations(&[proposal_id]).await;
+    }
+
+    /// Move every expired pending proposal to the expired proposals (anyone may call this)
+    async fn sweep_expired(&mut self) -> Result<MultisigResponse, MultisigError> {
+        let now = self.runtime.system_time().micros();
+
+        let pending_ids = self
+            .state
+            .pending_proposals
+            .indices()
+            .await
+            .expect("Failed to read pending proposals");
+
+        let mut proposal_ids = Vec::new();
+        for proposal_id in pending_ids {
+            let proposal = self
+                .state
+                .pending_proposals
+                .get(&proposal_id)
+                .await
+                .expect("Failed to get proposal")
+                .expect("Pending proposal disappeared");
+
+            if now > proposal.expires_at {
+                self.state
+                    .expired_proposals
+                    .insert(&proposal_id, proposal)
+                    .expect("Failed to store expired proposal");
+                self.state
+                    .pending_proposals
+                    .remove(&proposal_id)
+                    .expect("Failed to remove pending proposal");
+                self.emit(MultisigEvent::ProposalExpired { proposal_id });
+                proposal_ids.push(proposal_id);
+            }
+        }
+
+        self.purge_confirmations(&proposal_ids).await;
+
+        info!("Swept {} expired proposals", proposal_ids.len());
+
+        Ok(MultisigResponse::ExpiredProposalsSwept { proposal_ids })
+    }
+
+    /// Remove proposal IDs from every owner's confirmation list, dropping empty lists
+    async fn purge_confirmations(&mut self, proposal_ids: &[u64]) {
+        if proposal_ids.is_empty() {
+            return;
+        }
+
+        let confirmers = self
+            .state
+            .confirmations
+            .indices()
+            .await
+            .expect("Failed to read confirmations");
+
+        for owner in confirmers {
+            let mut confirmed_proposals = self
+                .state
+                .confirmations
+                .get(&owner)
+                .await
+                .unwrap()
+                .unwrap_or_default();
+            let before = confirmed_proposals.len();
+            confirmed_proposals.retain(|id| !proposal_ids.contains(id));
+
+            if confirmed_proposals.is_empty() {
+                self.state
+                    .confirmations
+                    .remove(&owner)
+                    .expect("Failed to remove confirmations");
+            } else if confirmed_proposals.len() != before {
+                self.state
+                    .confirmations
+                    .insert(&owner, confirmed_proposals)
+                    .expect("Failed to store confirmations");
+            }
+        }
+    }
+
+    /// Effective roles of an account: explicit roles, or every role for owners without any
+    async fn roles_of(&self, account: &AccountOwner) -> Vec<Role> {
+        let roles = self
+            .state
+            .roles
+            .get(account)
+            .await
+            .expect("Failed to read roles");
+
+        match roles {
+            Some(roles) => roles,
+            None if self.state.owners.get().contains(account) => Role::ALL.to_vec(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Whether an account holds a role
+    async fn has_role(&self, account: &AccountOwner, role: Role) -> bool {
+        self.roles_of(account).await.contains(&role)
+    }
+
+    /// Ensure the caller holds a role
+    async fn ensure_has_role(
+        &self,
+        caller: &AccountOwner,
+        role: Role,
+    ) -> Result<(), MultisigError> {
+        if !self.has_role(caller, role).await {
+            self.ensure_is_owner(caller)?;
+            return Err(MultisigError::MissingRole {
+                caller: *caller,
+                role,
+            });
+        }
+        Ok(())
+    }
+
+    /// Publish an event on the multisig stream
+    fn emit(&mut self, event: MultisigEvent) {
+        #[cfg_attr(not(test), allow(unused_variables))]
+        let index = self.runtime.emit(MULTISIG_STREAM_NAME.into(), &event);
+
+        // The mock runtime only reads back events that were added to it explicitly
+        #[cfg(test)]
+        {
+            let chain_id = self.runtime.chain_id();
+            let bytes = linera_sdk::bcs::to_bytes(&event).expect("Failed to serialize event");
+            self.runtime
+                .add_event(chain_id, MULTISIG_STREAM_NAME.into(), index, &bytes);
         }
     }
 
     /// Ensure the caller is an owner
-    fn ensure_is_owner(&self, caller: &AccountOwner) {
+    fn ensure_is_owner(&self, caller: &AccountOwner) -> Result<(), MultisigError> {
         let owners = self.state.owners.get();
         if !owners.contains(caller) {
-            panic!("Caller {:?} is not an owner", caller);
+            return Err(MultisigError::NotOwner(*caller));
         }
+        Ok(())
     }
 }
 
+/// Timestamp (microseconds) a number of seconds after another, saturating instead of overflowing
+fn micros_after(micros: u64, seconds: u64) -> u64 {
+    seconds
+        .checked_mul(1_000_000)
+        .map_or(u64::MAX, |delay| micros.saturating_add(delay))
+}
+
+/// Ensure transfer tiers are ordered, reachable and only the last one is unbounded
+fn validate_transfer_tiers(tiers: &[TransferTier], total_weight: u64) -> Result<(), MultisigError> {
+    for (index, tier) in tiers.iter().enumerate() {
+        if tier.threshold == 0 {
+            return Err(MultisigError::ZeroThreshold);
+        }
+        if tier.threshold > total_weight {
+            return Err(MultisigError::ThresholdAboveTotalWeight {
+                threshold: tier.threshold,
+                total_weight,
+            });
+        }
+        match (tier.up_to, tiers.get(index + 1)) {
+            (None, Some(_)) => return Err(MultisigError::UnboundedTierNotLast),
+            (Some(up_to), Some(next)) if next.up_to.is_some_and(|next| next <= up_to) => {
+                return Err(MultisigError::UnorderedTransferTiers);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Settings and map entries saved before validating a batch, restored once it is validated
+struct BatchSnapshot {
+    owners: Vec<AccountOwner>,
+    weights: BTreeMap<AccountOwner, u64>,
+    threshold: u64,
+    kind_thresholds: BTreeMap<ProposalKind, u64>,
+    veto_threshold: u64,
+    guardian: Option<AccountOwner>,
+    enabled_modules: Vec<ApplicationId>,
+    transfer_tiers: Vec<TransferTier>,
+    proposal_lifetime: u64,
+    time_delay: u64,
+    kind_time_delays: BTreeMap<ProposalKind, u64>,
+    /// Original explicit roles of the accounts whose roles changed
+    roles: BTreeMap<AccountOwner, Option<Vec<Role>>>,
+    /// Original allowances of the delegates whose allowance changed
+    allowances: BTreeMap<AccountOwner, Option<Allowance>>,
+}
+
 /// Instantiation arguments for the multisig
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct InstantiationArgs {
     /// Initial owners
     pub owners: Vec<AccountOwner>,
-    /// Number of confirmations required
+    /// Total confirmation weight required
     pub threshold: u64,
+    /// Voting weight per owner, in the same order as `owners` (optional, default: 1 each)
+    pub weights: Option<Vec<u64>>,
     /// Proposal lifetime in seconds (optional, default: 7 days = 604800s)
     pub proposal_lifetime: Option<u64>,
     /// Time-delay in seconds before execution (optional, default: 0 = disabled, Safe native)
     pub time_delay: Option<u64>,
+    /// Handling of pending proposals after owner or threshold changes (optional, default: keep)
+    pub pending_proposal_policy: Option<PendingProposalPolicy>,
+    /// Amount tiers for transfers, ordered by ascending `up_to` (optional, default: none)
+    pub transfer_tiers: Option<Vec<TransferTier>>,
+    /// Number of owner vetoes that cancel a proposal in its time-delay (optional, default: 0)
+    pub veto_threshold: Option<u64>,
+    /// Account whose veto alone cancels a proposal in its time-delay (optional)
+    pub guardian: Option<AccountOwner>,
 }
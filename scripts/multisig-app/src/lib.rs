@@ -4,7 +4,10 @@
 /*! ABI for the Linera Multisig Application */
 
 use async_graphql::{Request, Response};
-use linera_sdk::linera_base_types::{AccountOwner, ContractAbi, ServiceAbi};
+use linera_sdk::linera_base_types::{
+    Account, AccountOwner, AccountSignature, Amount, ApplicationId, BcsHashable, BcsSignable,
+    ChainId, ContractAbi, CryptoHash, ServiceAbi,
+};
 use serde::{Deserialize, Serialize};
 
 // Type alias for convenience
@@ -14,7 +17,11 @@ pub type Owner = AccountOwner;
 pub struct MultisigAbi;
 
 /// Multisig operations - All operations now go through proposal flow
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// When executed on a chain other than the multisig chain (the chain that created the
+/// application), the operation is forwarded to the multisig chain as a [`MultisigMessage`]
+/// authenticated by the signer of the block.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum MultisigOperation {
     /// Submit a new proposal (transfer, add owner, remove owner, etc.)
     SubmitProposal {
@@ -39,16 +46,215 @@ pub enum MultisigOperation {
         /// Proposal ID
         proposal_id: u64,
     },
+
+    /// Cancel a pending proposal (proposer only)
+    CancelProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Vote against a pending proposal
+    RejectProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Veto a proposal waiting out its time-delay (owners or the guardian)
+    VetoProposal {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Reason recorded with the veto
+        reason: String,
+    },
+
+    /// Move expired proposals out of the pending set (permissionless)
+    SweepExpired,
+
+    /// Spend from the caller's allowance without a proposal
+    SpendAllowance {
+        /// Destination account
+        to: Account,
+        /// Amount/value to send
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        value: Amount,
+    },
+
+    /// Move funds from the signer's account on the current chain into the treasury
+    ///
+    /// Executed directly on any chain: the funds are transferred to the multisig application
+    /// account on the multisig chain. Anyone can deposit.
+    Deposit {
+        /// Amount to deposit
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        amount: Amount,
+    },
+
+    /// Perform a transfer or application call on behalf of the calling enabled module
+    ///
+    /// Only accepted from an application in the enabled modules, calling on the multisig chain.
+    ExecuteFromModule {
+        /// Action to perform (`Transfer`, `CrossChainTransfer`, `TokenTransfer` or
+        /// `CallApplication`)
+        action: ProposalType,
+    },
+
+    /// Confirm a pending proposal on behalf of every owner who signed a [`ProposalApproval`]
+    /// of its digest
+    ///
+    /// The signer of the block only relays the signatures and needs no role.
+    ConfirmWithSignatures {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Owners' signatures over the approval of the proposal's digest
+        signatures: Vec<AccountSignature>,
+    },
+}
+
+/// Cross-chain messages sent from an owner's chain to the multisig chain
+///
+/// Each message carries the authentication of the signer that created it, which the multisig
+/// chain uses as the caller when applying the request.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum MultisigMessage {
+    /// Submit a new proposal from another chain
+    SubmitProposal {
+        /// Type of proposal
+        proposal_type: ProposalType,
+    },
+
+    /// Confirm a pending proposal from another chain
+    ConfirmProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Execute a confirmed proposal from another chain
+    ExecuteProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Revoke a confirmation from another chain
+    RevokeConfirmation {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Cancel a pending proposal from another chain
+    CancelProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Vote against a pending proposal from another chain
+    RejectProposal {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+
+    /// Veto a proposal waiting out its time-delay (owners or the guardian)
+    VetoProposal {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Reason recorded with the veto
+        reason: String,
+    },
+
+    /// Sweep expired proposals from another chain
+    SweepExpired,
+
+    /// Spend from the sender's allowance from another chain
+    SpendAllowance {
+        /// Destination account
+        to: Account,
+        /// Amount/value to send
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        value: Amount,
+    },
+
+    /// Tracked notice sent alongside a cross-chain transfer; it bounces back to the
+    /// multisig chain if the destination chain rejects the transfer
+    TransferReceipt {
+        /// ID of the proposal that executed the transfer
+        proposal_id: u64,
+        /// Destination account of the transfer
+        to: Account,
+        /// Amount transferred
+        value: Amount,
+    },
+
+    /// Confirm a pending proposal with owners' signatures relayed from another chain
+    ConfirmWithSignatures {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Owners' signatures over the approval of the proposal's digest
+        signatures: Vec<AccountSignature>,
+    },
+}
+
+impl TryFrom<MultisigOperation> for MultisigMessage {
+    type Error = MultisigError;
+
+    fn try_from(operation: MultisigOperation) -> Result<Self, Self::Error> {
+        let message = match operation {
+            MultisigOperation::SubmitProposal { proposal_type } => {
+                MultisigMessage::SubmitProposal { proposal_type }
+            }
+            MultisigOperation::ConfirmProposal { proposal_id } => {
+                MultisigMessage::ConfirmProposal { proposal_id }
+            }
+            MultisigOperation::ConfirmWithSignatures {
+                proposal_id,
+                signatures,
+            } => MultisigMessage::ConfirmWithSignatures {
+                proposal_id,
+                signatures,
+            },
+            MultisigOperation::ExecuteProposal { proposal_id } => {
+                MultisigMessage::ExecuteProposal { proposal_id }
+            }
+            MultisigOperation::RevokeConfirmation { proposal_id } => {
+                MultisigMessage::RevokeConfirmation { proposal_id }
+            }
+            MultisigOperation::CancelProposal { proposal_id } => {
+                MultisigMessage::CancelProposal { proposal_id }
+            }
+            MultisigOperation::RejectProposal { proposal_id } => {
+                MultisigMessage::RejectProposal { proposal_id }
+            }
+            MultisigOperation::VetoProposal {
+                proposal_id,
+                reason,
+            } => MultisigMessage::VetoProposal {
+                proposal_id,
+                reason,
+            },
+            MultisigOperation::SweepExpired => MultisigMessage::SweepExpired,
+            MultisigOperation::SpendAllowance { to, value } => {
+                MultisigMessage::SpendAllowance { to, value }
+            }
+            // Deposits transfer to the treasury directly and module actions run on the
+            // multisig chain, so neither has a message form
+            MultisigOperation::Deposit { .. } | MultisigOperation::ExecuteFromModule { .. } => {
+                return Err(MultisigError::NotForwardable);
+            }
+        };
+        Ok(message)
+    }
 }
 
 /// Type of proposal that can be submitted
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ProposalType {
-    /// Transfer funds to an address
-    Transfer {
+    /// Transfer a whole number of tokens to an address
+    ///
+    /// This is the original transfer proposal, kept at its position so that BCS-encoded
+    /// operations built against the previous ABI still decode. New clients should use
+    /// [`ProposalType::Transfer`].
+    LegacyTransfer {
         /// Destination address
         to: AccountOwner,
-        /// Amount/value to send
+        /// Number of whole tokens to send
         value: u64,
         /// Transaction data (calldata, function selector, etc.)
         data: Vec<u8>,
@@ -76,14 +282,393 @@ pub enum ProposalType {
 
     /// Change threshold
     ChangeThreshold {
-        /// New threshold value
+        /// New threshold value (total confirmation weight)
         threshold: u64,
     },
+
+    /// Change the voting weight of an owner (owners are added with weight 1)
+    SetOwnerWeight {
+        /// Owner whose weight changes
+        owner: AccountOwner,
+        /// New voting weight
+        weight: u64,
+    },
+
+    /// Call another application with the multisig as the authenticated caller
+    CallApplication {
+        /// Application to call
+        application_id: ApplicationId,
+        /// Operation of the target application, already serialized with its ABI
+        operation_bytes: Vec<u8>,
+    },
+
+    /// Grant (or replace) a spending allowance that bypasses the threshold
+    SetAllowance {
+        /// Owner or delegate allowed to spend
+        delegate: AccountOwner,
+        /// Maximum amount spendable per period
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        amount: Amount,
+        /// Period length in seconds after which the spent amount resets
+        period: u64,
+    },
+
+    /// Remove a spending allowance
+    RemoveAllowance {
+        /// Delegate whose allowance is removed
+        delegate: AccountOwner,
+    },
+
+    /// Set (or clear, with `None`) the threshold required for one kind of proposal
+    SetKindThreshold {
+        /// Kind of proposal
+        kind: ProposalKind,
+        /// Required confirmation weight (`None` falls back to the default threshold)
+        threshold: Option<u64>,
+    },
+
+    /// Allow an application to act through the multisig without per-action approval
+    EnableModule {
+        /// Module application
+        module: ApplicationId,
+    },
+
+    /// Revoke a module's ability to act through the multisig
+    DisableModule {
+        /// Module application
+        module: ApplicationId,
+    },
+
+    /// Set (or remove, with `None`) the guard application checking every execution
+    SetGuard {
+        /// Guard application implementing [`GuardAbi`]
+        guard: Option<ApplicationId>,
+    },
+
+    /// Change how long new proposals stay open
+    ChangeProposalLifetime {
+        /// Lifetime in seconds
+        lifetime: u64,
+    },
+
+    /// Change the default time-delay between reaching the threshold and execution
+    ChangeTimeDelay {
+        /// Time-delay in seconds (0 disables it)
+        time_delay: u64,
+    },
+
+    /// Set (or clear, with `None`) the time-delay for one kind of proposal
+    SetKindTimeDelay {
+        /// Kind of proposal
+        kind: ProposalKind,
+        /// Time-delay in seconds (`None` falls back to the default time-delay)
+        time_delay: Option<u64>,
+    },
+
+    /// Configure who can veto proposals during their time-delay
+    SetVetoPolicy {
+        /// Number of owner vetoes that cancel a proposal (0: owners cannot veto)
+        veto_threshold: u64,
+        /// Account whose veto alone cancels a proposal
+        guardian: Option<AccountOwner>,
+    },
+
+    /// Replace the amount tiers applied to transfers (an empty list removes them)
+    SetTransferTiers {
+        /// Tiers ordered by ascending `up_to`
+        tiers: Vec<TransferTier>,
+    },
+
+    /// Grant a role to an account (owners without explicit roles hold every role)
+    GrantRole {
+        /// Account receiving the role
+        account: AccountOwner,
+        /// Role to grant
+        role: Role,
+    },
+
+    /// Revoke a role from an account
+    RevokeRole {
+        /// Account losing the role
+        account: AccountOwner,
+        /// Role to revoke
+        role: Role,
+    },
+
+    /// Execute several steps atomically (batches cannot be nested)
+    Batch(Vec<ProposalType>),
+
+    /// Transfer funds to an account on any chain
+    CrossChainTransfer {
+        /// Destination account (chain ID and owner)
+        to: Account,
+        /// Amount/value to send
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        value: Amount,
+        /// Transaction data (calldata, function selector, etc.)
+        data: Vec<u8>,
+    },
+
+    /// Transfer tokens of a fungible-token application held by the multisig
+    TokenTransfer {
+        /// Fungible-token application (must be allowed by the application parameters)
+        token: ApplicationId,
+        /// Destination account (chain ID and owner)
+        to: Account,
+        /// Number of tokens to send
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        value: Amount,
+    },
+
+    /// Transfer funds to an address
+    Transfer {
+        /// Destination address
+        to: AccountOwner,
+        /// Amount/value to send
+        #[serde(deserialize_with = "amount_compat::deserialize")]
+        value: Amount,
+        /// Transaction data (calldata, function selector, etc.)
+        data: Vec<u8>,
+    },
+}
+
+impl ProposalType {
+    /// Kind of this proposal, used to look up its threshold
+    pub fn kind(&self) -> ProposalKind {
+        match self {
+            ProposalType::LegacyTransfer { .. } | ProposalType::Transfer { .. } => {
+                ProposalKind::Transfer
+            }
+            ProposalType::CrossChainTransfer { .. } => ProposalKind::CrossChainTransfer,
+            ProposalType::TokenTransfer { .. } => ProposalKind::TokenTransfer,
+            ProposalType::AddOwner { .. } => ProposalKind::AddOwner,
+            ProposalType::RemoveOwner { .. } => ProposalKind::RemoveOwner,
+            ProposalType::ReplaceOwner { .. } => ProposalKind::ReplaceOwner,
+            ProposalType::ChangeThreshold { .. } => ProposalKind::ChangeThreshold,
+            ProposalType::SetOwnerWeight { .. } => ProposalKind::SetOwnerWeight,
+            ProposalType::CallApplication { .. } => ProposalKind::CallApplication,
+            ProposalType::SetAllowance { .. } => ProposalKind::SetAllowance,
+            ProposalType::RemoveAllowance { .. } => ProposalKind::RemoveAllowance,
+            ProposalType::SetKindThreshold { .. } => ProposalKind::SetKindThreshold,
+            ProposalType::ChangeProposalLifetime { .. } => ProposalKind::ChangeProposalLifetime,
+            ProposalType::ChangeTimeDelay { .. } => ProposalKind::ChangeTimeDelay,
+            ProposalType::SetKindTimeDelay { .. } => ProposalKind::SetKindTimeDelay,
+            ProposalType::SetVetoPolicy { .. } => ProposalKind::SetVetoPolicy,
+            ProposalType::SetTransferTiers { .. } => ProposalKind::SetTransferTiers,
+            ProposalType::SetGuard { .. } => ProposalKind::SetGuard,
+            ProposalType::EnableModule { .. } => ProposalKind::EnableModule,
+            ProposalType::DisableModule { .. } => ProposalKind::DisableModule,
+            ProposalType::GrantRole { .. } => ProposalKind::GrantRole,
+            ProposalType::RevokeRole { .. } => ProposalKind::RevokeRole,
+            ProposalType::Batch(_) => ProposalKind::Batch,
+        }
+    }
+
+    /// Native value moved by this proposal (summed over the steps of a batch)
+    ///
+    /// Token transfers are not counted, since their values are in another unit.
+    pub fn transfer_value(&self) -> Amount {
+        match self {
+            ProposalType::LegacyTransfer { value, .. } => Amount::from_tokens((*value).into()),
+            ProposalType::Transfer { value, .. }
+            | ProposalType::CrossChainTransfer { value, .. } => *value,
+            ProposalType::Batch(steps) => steps
+                .iter()
+                .map(ProposalType::transfer_value)
+                .fold(Amount::ZERO, Amount::saturating_add),
+            _ => Amount::ZERO,
+        }
+    }
+
+    /// Whether this proposal only moves funds
+    pub fn is_transfer(&self) -> bool {
+        match self {
+            ProposalType::LegacyTransfer { .. }
+            | ProposalType::Transfer { .. }
+            | ProposalType::CrossChainTransfer { .. }
+            | ProposalType::TokenTransfer { .. } => true,
+            ProposalType::Batch(steps) => steps.iter().all(ProposalType::is_transfer),
+            _ => false,
+        }
+    }
+
+    /// Whether executing this proposal changes the owners, their weights or a threshold,
+    /// which starts a new configuration version
+    pub fn changes_owners_or_threshold(&self) -> bool {
+        match self {
+            ProposalType::AddOwner { .. }
+            | ProposalType::RemoveOwner { .. }
+            | ProposalType::ReplaceOwner { .. }
+            | ProposalType::ChangeThreshold { .. }
+            | ProposalType::SetOwnerWeight { .. }
+            | ProposalType::SetKindThreshold { .. }
+            | ProposalType::SetTransferTiers { .. } => true,
+            ProposalType::Batch(steps) => {
+                steps.iter().any(ProposalType::changes_owners_or_threshold)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Kind of proposal, one per [`ProposalType`] variant
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, async_graphql::Enum,
+)]
+pub enum ProposalKind {
+    /// [`ProposalType::Transfer`] and [`ProposalType::LegacyTransfer`]
+    Transfer,
+    /// [`ProposalType::CrossChainTransfer`]
+    CrossChainTransfer,
+    /// [`ProposalType::TokenTransfer`]
+    TokenTransfer,
+    /// [`ProposalType::AddOwner`]
+    AddOwner,
+    /// [`ProposalType::RemoveOwner`]
+    RemoveOwner,
+    /// [`ProposalType::ReplaceOwner`]
+    ReplaceOwner,
+    /// [`ProposalType::ChangeThreshold`]
+    ChangeThreshold,
+    /// [`ProposalType::SetOwnerWeight`]
+    SetOwnerWeight,
+    /// [`ProposalType::CallApplication`]
+    CallApplication,
+    /// [`ProposalType::SetAllowance`]
+    SetAllowance,
+    /// [`ProposalType::RemoveAllowance`]
+    RemoveAllowance,
+    /// [`ProposalType::SetKindThreshold`]
+    SetKindThreshold,
+    /// [`ProposalType::ChangeProposalLifetime`]
+    ChangeProposalLifetime,
+    /// [`ProposalType::ChangeTimeDelay`]
+    ChangeTimeDelay,
+    /// [`ProposalType::SetKindTimeDelay`]
+    SetKindTimeDelay,
+    /// [`ProposalType::SetVetoPolicy`]
+    SetVetoPolicy,
+    /// [`ProposalType::SetTransferTiers`]
+    SetTransferTiers,
+    /// [`ProposalType::SetGuard`]
+    SetGuard,
+    /// [`ProposalType::EnableModule`]
+    EnableModule,
+    /// [`ProposalType::DisableModule`]
+    DisableModule,
+    /// [`ProposalType::GrantRole`]
+    GrantRole,
+    /// [`ProposalType::RevokeRole`]
+    RevokeRole,
+    /// [`ProposalType::Batch`] (a batch also needs the threshold of each of its steps)
+    Batch,
+}
+
+/// Threshold override for one kind of proposal
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct KindThreshold {
+    /// Kind of proposal
+    pub kind: ProposalKind,
+    /// Required confirmation weight
+    pub threshold: u64,
+}
+
+/// Time-delay override for one kind of proposal
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct KindTimeDelay {
+    /// Kind of proposal
+    pub kind: ProposalKind,
+    /// Time-delay in seconds
+    pub time_delay: u64,
+}
+
+/// Cross-chain transfer rejected by its destination chain
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct BouncedTransfer {
+    /// ID of the proposal (or module action) that executed the transfer
+    pub proposal_id: u64,
+    /// Destination account that rejected the transfer
+    pub to: Account,
+    /// Amount transferred
+    pub value: Amount,
+    /// Timestamp when the rejection was received (microseconds)
+    pub bounced_at: u64,
+}
+
+/// Veto cast against a proposal during its time-delay
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct Veto {
+    /// Owner or guardian who vetoed
+    pub vetoer: AccountOwner,
+    /// Reason given for the veto
+    pub reason: String,
+    /// Timestamp of the veto (microseconds)
+    pub vetoed_at: u64,
+}
+
+/// Approval requirements for transfers up to a given value
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    async_graphql::SimpleObject,
+    Serialize,
+    Deserialize,
+)]
+pub struct TransferTier {
+    /// Largest transferred value covered by this tier (`None` for no upper bound)
+    #[serde(default, deserialize_with = "amount_compat::deserialize_option")]
+    pub up_to: Option<Amount>,
+    /// Required confirmation weight
+    pub threshold: u64,
+    /// Time-delay in seconds (`None` uses the default time-delay)
+    pub time_delay: Option<u64>,
+}
+
+/// Permission to perform a class of multisig operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, async_graphql::Enum)]
+pub enum Role {
+    /// Submit proposals of any type
+    Proposer,
+    /// Submit transfer proposals only
+    TransferProposer,
+    /// Confirm, revoke and reject proposals (confirmations only count for owners)
+    Confirmer,
+    /// Execute proposals that reached the threshold
+    Executor,
+    /// Cancel any pending proposal
+    Canceller,
+}
+
+impl Role {
+    /// Every role, held implicitly by owners without explicit role assignments
+    pub const ALL: [Role; 5] = [
+        Role::Proposer,
+        Role::TransferProposer,
+        Role::Confirmer,
+        Role::Executor,
+        Role::Canceller,
+    ];
+}
+
+/// How other pending proposals are handled after the owners or the threshold change
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, async_graphql::Enum,
+)]
+pub enum PendingProposalPolicy {
+    /// Cancel every other pending proposal
+    Invalidate,
+    /// Keep pending proposals and re-check them against the new owners and threshold
+    Reevaluate,
+    /// Keep pending proposals and their confirmations unchanged
+    #[default]
+    Keep,
 }
 
 impl ContractAbi for MultisigAbi {
     type Operation = MultisigOperation;
-    type Response = MultisigResponse;
+    type Response = Result<MultisigResponse, MultisigError>;
 }
 
 impl ServiceAbi for MultisigAbi {
@@ -91,8 +676,286 @@ impl ServiceAbi for MultisigAbi {
     type QueryResponse = Response;
 }
 
-/// Response types for multisig operations
+/// Application parameters, fixed when the multisig application is created
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MultisigParameters {
+    /// Fungible-token applications whose tokens the multisig may transfer
+    pub allowed_tokens: Vec<ApplicationId>,
+}
+
+/// Balance of the multisig in a fungible-token application
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct TokenBalance {
+    /// Fungible-token application
+    pub token: ApplicationId,
+    /// Balance held by the multisig application account
+    pub balance: Amount,
+}
+
+/// Content committed to by a proposal digest
+///
+/// The digest is the `CryptoHash` of the BCS encoding of this struct prefixed with its type
+/// name, which keeps it apart from the hashes of other Linera values. Binding the chain, the
+/// application and the configuration version means a digest (and any signature over it)
+/// cannot be replayed on another multisig, or after the proposal is re-evaluated under a new
+/// configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalContent {
+    /// Multisig chain
+    pub chain_id: ChainId,
+    /// Multisig application
+    pub application_id: ApplicationId,
+    /// Configuration version the proposal is valid under
+    pub config_version: u64,
+    /// Proposal ID
+    pub proposal_id: u64,
+    /// Proposed action
+    pub proposal_type: ProposalType,
+}
+
+impl BcsHashable<'_> for ProposalContent {}
+
+impl ProposalContent {
+    /// Canonical digest of the proposal
+    pub fn digest(&self) -> CryptoHash {
+        CryptoHash::new(self)
+    }
+}
+
+/// Statement signed off-chain by an owner to confirm a proposal
+///
+/// Owners sign it with their Linera account key, so the signature commits to the proposal
+/// digest and nothing else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalApproval {
+    /// Digest of the approved proposal
+    pub digest: CryptoHash,
+}
+
+impl BcsSignable<'_> for ProposalApproval {}
+
+/// Native balance of an account on the multisig chain
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct AccountBalance {
+    /// Account owner
+    pub owner: AccountOwner,
+    /// Balance of the account
+    pub balance: Amount,
+}
+
+/// ABI used to call arbitrary applications with pre-serialized operations
+///
+/// Operation bytes are passed through unchanged and the raw response bytes are returned, so
+/// the multisig does not need to know the ABI of the application it governs.
+pub struct RawApplicationAbi;
+
+impl ContractAbi for RawApplicationAbi {
+    type Operation = Vec<u8>;
+    type Response = Vec<u8>;
+
+    fn deserialize_operation(operation: Vec<u8>) -> Result<Self::Operation, String> {
+        Ok(operation)
+    }
+
+    fn serialize_operation(operation: &Self::Operation) -> Result<Vec<u8>, String> {
+        Ok(operation.clone())
+    }
+
+    fn deserialize_response(response: Vec<u8>) -> Result<Self::Response, String> {
+        Ok(response)
+    }
+
+    fn serialize_response(response: Self::Response) -> Result<Vec<u8>, String> {
+        Ok(response)
+    }
+}
+
+/// ABI that guard applications implement to vet proposal executions
+///
+/// The multisig calls the guard before executing a proposal and again with the execution's
+/// response. A [`GuardResponse::Rejected`] from either check aborts the execution.
+pub struct GuardAbi;
+
+impl ContractAbi for GuardAbi {
+    type Operation = GuardOperation;
+    type Response = GuardResponse;
+}
+
+/// Checks requested from a guard application
 #[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum GuardOperation {
+    /// Check a proposal before it is executed
+    CheckProposal {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Proposal to execute
+        proposal_type: ProposalType,
+        /// Owner executing the proposal
+        executor: AccountOwner,
+    },
+    /// Check a proposal after it was executed, in the same transaction
+    CheckAfterExecution {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Executed proposal
+        proposal_type: ProposalType,
+        /// Owner executing the proposal
+        executor: AccountOwner,
+        /// Response of the execution
+        response: MultisigResponse,
+    },
+}
+
+/// Verdict of a guard application
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum GuardResponse {
+    /// Execution may proceed
+    Approved,
+    /// Execution is vetoed
+    Rejected {
+        /// Reason reported to the executor
+        reason: String,
+    },
+}
+
+/// Longest accepted proposal lifetime in seconds (one year), which also bounds time-delays
+pub const MAX_PROPOSAL_LIFETIME: u64 = 365 * 24 * 60 * 60;
+
+/// Longest accepted allowance period in seconds (one year)
+pub const MAX_ALLOWANCE_PERIOD: u64 = 365 * 24 * 60 * 60;
+
+/// Largest accepted total owner weight, which keeps every sum of weights from overflowing
+pub const MAX_TOTAL_WEIGHT: u64 = u32::MAX as u64;
+
+/// Name of the stream on which the multisig publishes its [`MultisigEvent`]s
+pub const MULTISIG_STREAM_NAME: &[u8] = b"multisig_events";
+
+/// Events published on [`MULTISIG_STREAM_NAME`] for indexers and subscribing applications
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MultisigEvent {
+    /// Proposal submitted
+    ProposalSubmitted {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Account that submitted the proposal
+        proposer: AccountOwner,
+        /// Submitted proposal
+        proposal_type: ProposalType,
+    },
+    /// Proposal confirmed by an owner
+    ProposalConfirmed {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Confirming owner
+        owner: AccountOwner,
+        /// Confirmation weight from current owners
+        confirmation_weight: u64,
+    },
+    /// Confirmation revoked by an owner
+    ConfirmationRevoked {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Revoking owner
+        owner: AccountOwner,
+    },
+    /// Proposal reached its threshold
+    ThresholdReached {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Earliest execution timestamp (microseconds, 0 without a time-delay)
+        executable_after: u64,
+    },
+    /// Proposal rejected by an owner
+    ProposalRejected {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Rejecting owner
+        owner: AccountOwner,
+    },
+    /// Proposal vetoed during its time-delay
+    ProposalVetoed {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Owner or guardian who vetoed
+        vetoer: AccountOwner,
+        /// Reason given for the veto
+        reason: String,
+    },
+    /// Proposal cancelled (by its proposer, a canceller, rejections, vetoes or the policy)
+    ProposalCancelled {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+    /// Proposal expired before execution
+    ProposalExpired {
+        /// Proposal ID
+        proposal_id: u64,
+    },
+    /// Proposal (or module action) executed
+    ProposalExecuted {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Account that executed the proposal
+        executor: AccountOwner,
+        /// Response of the execution
+        response: MultisigResponse,
+    },
+    /// Owner added
+    OwnerAdded {
+        /// New owner
+        owner: AccountOwner,
+    },
+    /// Owner removed
+    OwnerRemoved {
+        /// Removed owner
+        owner: AccountOwner,
+    },
+    /// Owner replaced
+    OwnerReplaced {
+        /// Previous owner
+        old_owner: AccountOwner,
+        /// New owner
+        new_owner: AccountOwner,
+    },
+    /// Owner weight changed
+    OwnerWeightChanged {
+        /// Owner
+        owner: AccountOwner,
+        /// New weight
+        weight: u64,
+    },
+    /// Default threshold changed
+    ThresholdChanged {
+        /// New threshold
+        threshold: u64,
+    },
+    /// Funds transferred out of the multisig
+    FundsTransferred {
+        /// Destination account
+        to: Account,
+        /// Amount transferred
+        value: Amount,
+    },
+    /// Fungible tokens transferred out of the multisig
+    TokensTransferred {
+        /// Fungible-token application
+        token: ApplicationId,
+        /// Destination account
+        to: Account,
+        /// Number of tokens transferred
+        value: Amount,
+    },
+    /// Funds deposited into the treasury
+    Deposited {
+        /// Account the funds came from
+        depositor: AccountOwner,
+        /// Amount deposited
+        amount: Amount,
+    },
+}
+
+/// Response types for multisig operations
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum MultisigResponse {
     /// Proposal submitted successfully
     ProposalSubmitted {
@@ -116,6 +979,34 @@ pub enum MultisigResponse {
         /// ID of the proposal
         proposal_id: u64,
     },
+    /// Proposal rejected by an owner
+    ProposalRejected {
+        /// ID of the proposal
+        proposal_id: u64,
+        /// Current number of rejections
+        rejections: u64,
+        /// Whether the proposal was cancelled because the threshold became unreachable
+        cancelled: bool,
+    },
+    /// Veto recorded against a proposal in its time-delay
+    ProposalVetoed {
+        /// ID of the proposal
+        proposal_id: u64,
+        /// Current number of vetoes from owners
+        vetoes: u64,
+        /// Whether the veto cancelled the proposal
+        cancelled: bool,
+    },
+    /// Proposal cancelled
+    ProposalCancelled {
+        /// ID of the cancelled proposal
+        proposal_id: u64,
+    },
+    /// Expired proposals swept
+    ExpiredProposalsSwept {
+        /// IDs of the proposals moved to the expired set
+        proposal_ids: Vec<u64>,
+    },
     /// Owner added (after proposal execution)
     OwnerAdded {
         /// Address of the added owner
@@ -143,10 +1034,438 @@ pub enum MultisigResponse {
         /// Destination address
         to: AccountOwner,
         /// Amount transferred
-        value: u64,
+        value: Amount,
+    },
+    /// Funds transferred to an account on another chain (after proposal execution)
+    FundsTransferredToAccount {
+        /// Destination account
+        to: Account,
+        /// Amount transferred
+        value: Amount,
+    },
+    /// Fungible tokens transferred (after proposal execution)
+    TokensTransferred {
+        /// Fungible-token application
+        token: ApplicationId,
+        /// Destination account
+        to: Account,
+        /// Number of tokens transferred
+        value: Amount,
+    },
+    /// Application called (after proposal execution)
+    ApplicationCalled {
+        /// Application that was called
+        application_id: ApplicationId,
+        /// Raw response returned by the application
+        response: Vec<u8>,
+    },
+    /// Allowance granted (after proposal execution)
+    AllowanceSet {
+        /// Delegate allowed to spend
+        delegate: AccountOwner,
+        /// Maximum amount spendable per period
+        amount: Amount,
+        /// Period length in seconds
+        period: u64,
+    },
+    /// Allowance removed (after proposal execution)
+    AllowanceRemoved {
+        /// Delegate whose allowance was removed
+        delegate: AccountOwner,
+    },
+    /// Funds transferred from an allowance
+    AllowanceSpent {
+        /// Delegate who spent the allowance
+        delegate: AccountOwner,
+        /// Destination account
+        to: Account,
+        /// Amount transferred
+        value: Amount,
+        /// Amount left in the current period
+        remaining: Amount,
+    },
+    /// Owner weight changed (after proposal execution)
+    OwnerWeightChanged {
+        /// Owner whose weight changed
+        owner: AccountOwner,
+        /// New voting weight
+        weight: u64,
+    },
+    /// Threshold for a kind of proposal changed (after proposal execution)
+    KindThresholdChanged {
+        /// Kind of proposal
+        kind: ProposalKind,
+        /// New threshold (`None` when reset to the default threshold)
+        threshold: Option<u64>,
+    },
+    /// Module enabled (after proposal execution)
+    ModuleEnabled {
+        /// Module application
+        module: ApplicationId,
+    },
+    /// Module disabled (after proposal execution)
+    ModuleDisabled {
+        /// Module application
+        module: ApplicationId,
+    },
+    /// Action performed on behalf of an enabled module
+    ModuleActionExecuted {
+        /// Module application
+        module: ApplicationId,
+        /// ID under which the action is recorded in the executed proposals
+        proposal_id: u64,
+        /// Response of the action
+        response: Box<MultisigResponse>,
+    },
+    /// Guard application changed (after proposal execution)
+    GuardChanged {
+        /// New guard (`None` when removed)
+        guard: Option<ApplicationId>,
+    },
+    /// Proposal lifetime changed (after proposal execution)
+    ProposalLifetimeChanged {
+        /// New lifetime in seconds
+        lifetime: u64,
+    },
+    /// Default time-delay changed (after proposal execution)
+    TimeDelayChanged {
+        /// New time-delay in seconds
+        time_delay: u64,
+    },
+    /// Time-delay for a kind of proposal changed (after proposal execution)
+    KindTimeDelayChanged {
+        /// Kind of proposal
+        kind: ProposalKind,
+        /// New time-delay (`None` when reset to the default time-delay)
+        time_delay: Option<u64>,
+    },
+    /// Veto policy changed (after proposal execution)
+    VetoPolicyChanged {
+        /// Number of owner vetoes that cancel a proposal
+        veto_threshold: u64,
+        /// Account whose veto alone cancels a proposal
+        guardian: Option<AccountOwner>,
+    },
+    /// Transfer tiers replaced (after proposal execution)
+    TransferTiersChanged {
+        /// Number of tiers now in effect
+        tier_count: u64,
+    },
+    /// Role granted (after proposal execution)
+    RoleGranted {
+        /// Account receiving the role
+        account: AccountOwner,
+        /// Granted role
+        role: Role,
+    },
+    /// Role revoked (after proposal execution)
+    RoleRevoked {
+        /// Account losing the role
+        account: AccountOwner,
+        /// Revoked role
+        role: Role,
+    },
+    /// Batch executed (after proposal execution)
+    BatchExecuted {
+        /// Response of each step, in order
+        responses: Vec<MultisigResponse>,
+    },
+    /// Funds deposited into the treasury
+    Deposited {
+        /// Account the funds came from
+        depositor: AccountOwner,
+        /// Amount deposited
+        amount: Amount,
+    },
+    /// Operation forwarded to the multisig chain as a cross-chain message
+    RequestForwarded {
+        /// Chain ID of the multisig chain
+        chain_id: ChainId,
     },
 }
 
+/// Reasons a multisig operation can fail
+///
+/// Operations return these errors instead of aborting, so that callers can react to specific
+/// failures. Cross-chain requests that fail are still rejected so that they bounce back.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, thiserror::Error)]
+pub enum MultisigError {
+    /// The operation was not signed
+    #[error("Operation must be authenticated")]
+    NotAuthenticated,
+    /// A module action was not called by an application
+    #[error("Module actions must be called by an application")]
+    NotCalledByApplication,
+    /// A module action was called on another chain than the multisig chain
+    #[error("Module actions must run on the multisig chain")]
+    NotOnMultisigChain,
+    /// The caller is not an owner
+    #[error("Caller {0} is not an owner")]
+    NotOwner(AccountOwner),
+    /// The caller does not hold the role required by the operation
+    #[error("Caller {caller} does not have the {role:?} role")]
+    MissingRole {
+        /// Caller of the operation
+        caller: AccountOwner,
+        /// Required role
+        role: Role,
+    },
+    /// The caller may not submit this type of proposal
+    #[error("Caller {0} is not allowed to submit this proposal")]
+    NotAllowedToPropose(AccountOwner),
+    /// The caller is neither the proposer nor a canceller
+    #[error("Only the proposer or a canceller can cancel proposal {0}")]
+    NotAllowedToCancel(u64),
+    /// No pending proposal has this ID
+    #[error("Proposal {0} not found")]
+    ProposalNotFound(u64),
+    /// The proposal was already executed
+    #[error("Proposal {0} already executed")]
+    ProposalAlreadyExecuted(u64),
+    /// The proposal expired before it was executed
+    #[error("Proposal expired: current time {now} > expiration {expires_at}")]
+    ProposalExpired {
+        /// Current time in microseconds
+        now: u64,
+        /// Expiration time in microseconds
+        expires_at: u64,
+    },
+    /// The proposal was created under an older configuration (invalidate policy)
+    #[error(
+        "Proposal was created under configuration version {proposal_version} \
+        (current: {current_version})"
+    )]
+    StaleConfigurationVersion {
+        /// Configuration version of the proposal
+        proposal_version: u64,
+        /// Current configuration version
+        current_version: u64,
+    },
+    /// The proposal lacks confirmation weight
+    #[error("Insufficient confirmations: {confirmation_weight} < {threshold} (required)")]
+    InsufficientConfirmations {
+        /// Weight of the current confirmations
+        confirmation_weight: u64,
+        /// Required threshold
+        threshold: u64,
+    },
+    /// The proposal is still waiting out its time-delay
+    #[error("Time-delay not met: must wait {wait_seconds} more seconds")]
+    TimeDelayNotMet {
+        /// Remaining time-delay in seconds
+        wait_seconds: u64,
+    },
+    /// The guard application rejected the execution
+    #[error("Guard rejected proposal {proposal_id}: {reason}")]
+    GuardRejected {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Reason given by the guard
+        reason: String,
+    },
+    /// A batch has no steps
+    #[error("Batch must contain at least one step")]
+    EmptyBatch,
+    /// A batch contains another batch
+    #[error("Batches cannot be nested")]
+    NestedBatch,
+    /// A transfer of zero tokens
+    #[error("Transfer amount must be greater than 0")]
+    ZeroAmount,
+    /// The account is already an owner
+    #[error("Owner {0} already exists")]
+    OwnerAlreadyExists(AccountOwner),
+    /// The account is not an owner
+    #[error("Owner {0} not found")]
+    OwnerNotFound(AccountOwner),
+    /// The change would leave a threshold out of reach of the owners' total weight
+    #[error("Change would make a threshold impossible to reach")]
+    ThresholdUnreachable,
+    /// A threshold of zero
+    #[error("Threshold cannot be zero")]
+    ZeroThreshold,
+    /// A threshold above the owners' total weight
+    #[error("Threshold {threshold} cannot exceed total owner weight {total_weight}")]
+    ThresholdAboveTotalWeight {
+        /// Requested threshold
+        threshold: u64,
+        /// Total owner weight
+        total_weight: u64,
+    },
+    /// An owner weight of zero
+    #[error("Owner weight must be greater than 0")]
+    ZeroWeight,
+    /// A proposal lifetime of zero
+    #[error("Proposal lifetime must be greater than 0")]
+    ZeroProposalLifetime,
+    /// A proposal lifetime that does not exceed every time-delay
+    #[error("Proposal lifetime {lifetime}s must exceed every time-delay ({max_time_delay}s)")]
+    ProposalLifetimeTooShort {
+        /// Requested lifetime in seconds
+        lifetime: u64,
+        /// Longest configured time-delay in seconds
+        max_time_delay: u64,
+    },
+    /// A proposal lifetime above [`MAX_PROPOSAL_LIFETIME`]
+    #[error("Proposal lifetime {lifetime}s cannot exceed {max_lifetime}s")]
+    ProposalLifetimeTooLong {
+        /// Requested lifetime in seconds
+        lifetime: u64,
+        /// Longest accepted lifetime in seconds
+        max_lifetime: u64,
+    },
+    /// A time-delay that is not shorter than the proposal lifetime
+    #[error("Time-delay {time_delay}s must be shorter than the lifetime ({proposal_lifetime}s)")]
+    TimeDelayTooLong {
+        /// Requested time-delay in seconds
+        time_delay: u64,
+        /// Proposal lifetime in seconds
+        proposal_lifetime: u64,
+    },
+    /// A veto threshold above the number of owners
+    #[error("Veto threshold {veto_threshold} cannot exceed the number of owners ({owner_count})")]
+    VetoThresholdTooHigh {
+        /// Requested veto threshold
+        veto_threshold: u64,
+        /// Number of owners
+        owner_count: u64,
+    },
+    /// The operation cannot be relayed to the multisig chain as a message
+    #[error("Operation cannot be forwarded to the multisig chain")]
+    NotForwardable,
+    /// The multisig application was set as its own guard, module or call target
+    #[error("The multisig application cannot target itself")]
+    SelfReference,
+    /// The module is already enabled
+    #[error("Module {0} is already enabled")]
+    ModuleAlreadyEnabled(ApplicationId),
+    /// The application is not an enabled module
+    #[error("Application {0} is not an enabled module")]
+    ModuleNotEnabled(ApplicationId),
+    /// A module asked for something other than a transfer or an application call
+    #[error("Modules can only perform transfers and application calls")]
+    UnsupportedModuleAction,
+    /// An allowance of zero tokens
+    #[error("Allowance amount must be greater than 0")]
+    ZeroAllowance,
+    /// An allowance period of zero seconds
+    #[error("Allowance period must be greater than 0")]
+    ZeroAllowancePeriod,
+    /// An allowance period above [`MAX_ALLOWANCE_PERIOD`]
+    #[error("Allowance period {period}s cannot exceed {max_period}s")]
+    AllowancePeriodTooLong {
+        /// Requested period in seconds
+        period: u64,
+        /// Longest accepted period in seconds
+        max_period: u64,
+    },
+    /// The delegate has no allowance
+    #[error("No allowance for {0}")]
+    AllowanceNotFound(AccountOwner),
+    /// The spend exceeds what remains of the allowance in this period
+    #[error("Allowance exceeded: requested={requested}, remaining={remaining}")]
+    AllowanceExceeded {
+        /// Requested value
+        requested: Amount,
+        /// Remaining allowance in this period
+        remaining: Amount,
+    },
+    /// The account already holds the role
+    #[error("Account {account} already has role {role:?}")]
+    RoleAlreadyGranted {
+        /// Account
+        account: AccountOwner,
+        /// Role
+        role: Role,
+    },
+    /// The account does not hold the role
+    #[error("Account {account} does not have role {role:?}")]
+    RoleNotGranted {
+        /// Account
+        account: AccountOwner,
+        /// Role
+        role: Role,
+    },
+    /// An application call without an operation
+    #[error("Application call must include an operation")]
+    EmptyApplicationCall,
+    /// The fungible-token application is not allowed by the application parameters
+    #[error("Token application {0} is not allowed")]
+    TokenNotAllowed(ApplicationId),
+    /// An unbounded transfer tier that is not the last one
+    #[error("Only the last transfer tier can be unbounded")]
+    UnboundedTierNotLast,
+    /// Transfer tiers that are not ordered by ascending `up_to`
+    #[error("Transfer tiers must be ordered by ascending up_to")]
+    UnorderedTransferTiers,
+    /// Owner vetoes are disabled (veto threshold of zero)
+    #[error("Owner vetoes are disabled")]
+    OwnerVetoesDisabled,
+    /// The proposal is not waiting out its time-delay
+    #[error("Proposal {0} is not in its time-delay")]
+    NotInTimeDelay(u64),
+    /// The vetoer already vetoed the proposal
+    #[error("{vetoer} already vetoed proposal {proposal_id}")]
+    AlreadyVetoed {
+        /// Vetoer
+        vetoer: AccountOwner,
+        /// Proposal ID
+        proposal_id: u64,
+    },
+    /// A signature confirmation without signatures
+    #[error("At least one signature is required")]
+    NoSignatures,
+    /// A signature that does not match the proposal's approval
+    #[error("Invalid signature from {0}")]
+    InvalidSignature(AccountOwner),
+    /// The treasury balance does not cover the transfer
+    #[error("Insufficient balance: required={required}, available={available}")]
+    InsufficientBalance {
+        /// Amount to transfer
+        required: Amount,
+        /// Balance of the multisig application account
+        available: Amount,
+    },
+    /// A relayed signature from an owner who revoked their confirmation of the proposal
+    #[error("Owner {owner} revoked their approval of proposal {proposal_id}")]
+    ApprovalRevoked {
+        /// Proposal ID
+        proposal_id: u64,
+        /// Owner who revoked their confirmation
+        owner: AccountOwner,
+    },
+    /// The total owner weight would exceed [`MAX_TOTAL_WEIGHT`]
+    #[error("Total owner weight cannot exceed {max_total_weight}")]
+    TotalWeightTooHigh {
+        /// Largest accepted total owner weight
+        max_total_weight: u64,
+    },
+}
+
+/// Owner together with its voting weight
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct WeightedOwner {
+    /// Owner address
+    pub owner: AccountOwner,
+    /// Voting weight
+    pub weight: u64,
+}
+
+/// Spending allowance granted to an owner or delegate
+#[derive(Debug, Clone, PartialEq, Eq, async_graphql::SimpleObject, Serialize, Deserialize)]
+pub struct Allowance {
+    /// Owner or delegate allowed to spend
+    pub delegate: AccountOwner,
+    /// Maximum amount spendable per period
+    pub amount: Amount,
+    /// Period length in seconds
+    pub period: u64,
+    /// Amount spent in the current period
+    pub spent: Amount,
+    /// Start of the current period (microseconds)
+    pub period_start: u64,
+}
+
 /// Proposal view for GraphQL queries
 #[derive(Debug, Clone, async_graphql::SimpleObject, serde::Serialize, serde::Deserialize)]
 pub struct ProposalView {
@@ -156,10 +1475,94 @@ pub struct ProposalView {
     pub proposal_type: String,
     /// Owner who created the proposal
     pub proposer: Owner,
-    /// Number of confirmations
+    /// Number of confirmations from current owners
     pub confirmation_count: u64,
+    /// Weight of the confirmations from current owners
+    pub confirmation_weight: u64,
+    /// Confirmation weight required for this proposal's kind
+    pub required_threshold: u64,
+    /// Whether the confirmations reach the required threshold
+    pub threshold_reached: bool,
+    /// Time-delay in seconds applied once the threshold is reached
+    pub required_time_delay: u64,
+    /// Expiration timestamp (microseconds)
+    pub expires_at: u64,
+    /// Earliest execution timestamp (microseconds, 0 while no time-delay is running)
+    pub executable_after: u64,
+    /// Owners who confirmed (including owners removed since)
+    pub confirmers: Vec<Owner>,
+    /// Number of rejections from current owners
+    pub rejection_count: u64,
+    /// Weight of the rejections from current owners
+    pub rejection_weight: u64,
+    /// Number of vetoes from current owners
+    pub veto_count: u64,
+    /// Vetoes cast during the time-delay, with their reasons
+    pub vetoes: Vec<Veto>,
     /// Whether executed
     pub executed: bool,
+    /// Whether cancelled
+    pub cancelled: bool,
     /// Creation timestamp
     pub created_at: u64,
+    /// Configuration version the proposal was created (or last re-evaluated) under
+    pub config_version: u64,
+    /// Canonical digest of the proposal, signed by owners confirming off-chain
+    pub digest: CryptoHash,
+    /// Response recorded at execution (as string representation)
+    pub response: Option<String>,
+}
+
+/// Serde helpers reading amounts from either format accepted by the ABI
+///
+/// Amounts are `Amount` strings with up to 18 decimals (e.g. `"1.5"`). Human-readable
+/// formats (JSON and GraphQL input) also accept the plain `u64` token counts sent by older
+/// clients, which are read as whole tokens.
+///
+/// Compatibility stops there: BCS carries no type information to tell the two apart, and an
+/// `Amount` (16-byte `u128`) does not share the layout of the former `u64` fields, so binary
+/// operations encoded for the previous ABI are rejected rather than reinterpreted.
+pub mod amount_compat {
+    use linera_sdk::linera_base_types::Amount;
+    use serde::{Deserialize, Deserializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CompatAmount {
+        Tokens(u64),
+        Amount(Amount),
+    }
+
+    impl From<CompatAmount> for Amount {
+        fn from(amount: CompatAmount) -> Self {
+            match amount {
+                CompatAmount::Tokens(tokens) => Amount::from_tokens(tokens.into()),
+                CompatAmount::Amount(amount) => amount,
+            }
+        }
+    }
+
+    /// Deserialize an `Amount`, or a `u64` number of whole tokens
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            CompatAmount::deserialize(deserializer).map(Amount::from)
+        } else {
+            Amount::deserialize(deserializer)
+        }
+    }
+
+    /// Deserialize an optional `Amount`, or an optional `u64` number of whole tokens
+    pub fn deserialize_option<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Ok(Option::<CompatAmount>::deserialize(deserializer)?.map(Amount::from))
+        } else {
+            Option::<Amount>::deserialize(deserializer)
+        }
+    }
 }
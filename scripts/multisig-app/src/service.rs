@@ -5,13 +5,20 @@
 
 use std::sync::Arc;
 
-use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Request, Response, Result, Schema};
+use async_graphql::{
+    Context, EmptyMutation, EmptySubscription, Object, Request, Response, Result, Schema,
+};
+use linera_multisig::{
+    AccountBalance, Allowance, BouncedTransfer, KindThreshold, KindTimeDelay, MultisigAbi,
+    MultisigParameters, Owner, PendingProposalPolicy, ProposalView, Role, TokenBalance,
+    TransferTier, WeightedOwner,
+};
 use linera_sdk::{
-    linera_base_types::WithServiceAbi,
+    abis::fungible::FungibleTokenAbi,
+    linera_base_types::{AccountOwner, Amount, ApplicationId, WithServiceAbi},
     views::View,
     Service, ServiceRuntime,
 };
-use linera_multisig::{MultisigAbi, Owner, ProposalView};
 
 mod state;
 use state::{MultisigState, Proposal, ProposalType};
@@ -19,6 +26,7 @@ use state::{MultisigState, Proposal, ProposalType};
 /// Multisig service implementation
 pub struct MultisigService {
     state: Arc<MultisigState>,
+    runtime: Arc<ServiceRuntime<Self>>,
 }
 
 linera_sdk::service!(MultisigService);
@@ -28,7 +36,7 @@ impl WithServiceAbi for MultisigService {
 }
 
 impl Service for MultisigService {
-    type Parameters = ();
+    type Parameters = MultisigParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = MultisigState::load(runtime.root_view_storage_context())
@@ -36,17 +44,15 @@ impl Service for MultisigService {
             .expect("Failed to load state");
         MultisigService {
             state: Arc::new(state),
+            runtime: Arc::new(runtime),
         }
     }
 
     async fn handle_query(&self, request: Request) -> Response {
-        let schema = Schema::build(
-            QueryRoot,
-            EmptyMutation,
-            EmptySubscription,
-        )
-        .data(self.state.clone())
-        .finish();
+        let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+            .data(self.state.clone())
+            .data(self.runtime.clone())
+            .finish();
         schema.execute(request).await
     }
 }
@@ -62,30 +68,164 @@ impl QueryRoot {
         Ok(state.owners.get().clone())
     }
 
-    /// Get the current threshold
+    /// Get the owners with their voting weights
+    async fn owner_weights(&self, ctx: &Context<'_>) -> Result<Vec<WeightedOwner>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state
+            .owners
+            .get()
+            .iter()
+            .map(|owner| WeightedOwner {
+                owner: *owner,
+                weight: state.weight_of(owner),
+            })
+            .collect())
+    }
+
+    /// Get the total voting weight of all owners
+    async fn total_weight(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.total_weight())
+    }
+
+    /// Get the current threshold (total confirmation weight required)
     async fn threshold(&self, ctx: &Context<'_>) -> Result<u64> {
         let state = ctx.data::<Arc<MultisigState>>()?;
         Ok(*state.threshold.get())
     }
 
+    /// Get the thresholds overriding the default for specific kinds of proposals
+    async fn kind_thresholds(&self, ctx: &Context<'_>) -> Result<Vec<KindThreshold>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state
+            .kind_thresholds
+            .get()
+            .iter()
+            .map(|(kind, threshold)| KindThreshold {
+                kind: *kind,
+                threshold: *threshold,
+            })
+            .collect())
+    }
+
+    /// Get the highest threshold of any kind or transfer tier
+    async fn max_threshold(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.max_threshold())
+    }
+
+    /// Get the lifetime of new proposals in seconds
+    async fn proposal_lifetime(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.proposal_lifetime.get())
+    }
+
+    /// Get the default time-delay in seconds
+    async fn time_delay(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.time_delay.get())
+    }
+
+    /// Get the time-delays overriding the default for specific kinds of proposals
+    async fn kind_time_delays(&self, ctx: &Context<'_>) -> Result<Vec<KindTimeDelay>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state
+            .kind_time_delays
+            .get()
+            .iter()
+            .map(|(kind, time_delay)| KindTimeDelay {
+                kind: *kind,
+                time_delay: *time_delay,
+            })
+            .collect())
+    }
+
+    /// Get the longest time-delay of any kind or transfer tier
+    async fn max_time_delay(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.max_time_delay())
+    }
+
+    /// Get the number of owner vetoes that cancel a proposal in its time-delay
+    async fn veto_threshold(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.veto_threshold.get())
+    }
+
+    /// Get the account whose veto alone cancels a proposal, if any
+    async fn guardian(&self, ctx: &Context<'_>) -> Result<Option<Owner>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.guardian.get())
+    }
+
+    /// Get the applications allowed to act through the multisig
+    async fn enabled_modules(&self, ctx: &Context<'_>) -> Result<Vec<ApplicationId>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.enabled_modules.get().clone())
+    }
+
+    /// Get the guard application vetting proposal executions, if any
+    async fn guard(&self, ctx: &Context<'_>) -> Result<Option<ApplicationId>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.guard.get())
+    }
+
+    /// Get the amount tiers applied to transfers
+    async fn transfer_tiers(&self, ctx: &Context<'_>) -> Result<Vec<TransferTier>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.transfer_tiers.get().clone())
+    }
+
+    /// Get the tier applied to a transfer of the given value, if any tiers are configured
+    async fn transfer_tier(
+        &self,
+        ctx: &Context<'_>,
+        value: Amount,
+    ) -> Result<Option<TransferTier>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.transfer_tier(value).cloned())
+    }
+
     /// Get the current nonce (next proposal ID)
     async fn nonce(&self, ctx: &Context<'_>) -> Result<u64> {
         let state = ctx.data::<Arc<MultisigState>>()?;
         Ok(*state.nonce.get())
     }
 
+    /// Get the current configuration version
+    async fn config_version(&self, ctx: &Context<'_>) -> Result<u64> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.config_version.get())
+    }
+
+    /// Get how pending proposals are handled after owner or threshold changes
+    async fn pending_proposal_policy(&self, ctx: &Context<'_>) -> Result<PendingProposalPolicy> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(*state.pending_proposal_policy.get())
+    }
+
     /// Get a proposal by ID
     async fn proposal(&self, ctx: &Context<'_>, id: u64) -> Result<Option<ProposalView>> {
         let state = ctx.data::<Arc<MultisigState>>()?;
-        
+
         // Check pending proposals first
         if let Some(proposal) = state.pending_proposals.get(&id).await? {
-            return Ok(Some(proposal_to_view(proposal)));
+            return Ok(Some(proposal_to_view(proposal, state)));
         }
-        
+
         // Then check executed proposals
         if let Some(proposal) = state.executed_proposals.get(&id).await? {
-            return Ok(Some(proposal_to_view(proposal)));
+            return Ok(Some(proposal_to_view(proposal, state)));
+        }
+
+        // Then check cancelled proposals
+        if let Some(proposal) = state.cancelled_proposals.get(&id).await? {
+            return Ok(Some(proposal_to_view(proposal, state)));
+        }
+
+        // Finally check expired proposals
+        if let Some(proposal) = state.expired_proposals.get(&id).await? {
+            return Ok(Some(proposal_to_view(proposal, state)));
         }
         
         Ok(None)
@@ -101,7 +241,7 @@ impl QueryRoot {
         let indices = state.pending_proposals.indices().await?;
         for key in indices {
             if let Some(proposal) = state.pending_proposals.get(&key).await? {
-                proposals.push(proposal_to_view(proposal));
+                proposals.push(proposal_to_view(proposal, state));
             }
         }
         
@@ -118,13 +258,136 @@ impl QueryRoot {
         let indices = state.executed_proposals.indices().await?;
         for key in indices {
             if let Some(proposal) = state.executed_proposals.get(&key).await? {
-                proposals.push(proposal_to_view(proposal));
+                proposals.push(proposal_to_view(proposal, state));
             }
         }
         
         Ok(proposals)
     }
 
+    /// Get all cancelled proposals
+    async fn cancelled_proposals(&self, ctx: &Context<'_>) -> Result<Vec<ProposalView>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        let mut proposals = Vec::new();
+
+        let indices = state.cancelled_proposals.indices().await?;
+        for key in indices {
+            if let Some(proposal) = state.cancelled_proposals.get(&key).await? {
+                proposals.push(proposal_to_view(proposal, state));
+            }
+        }
+
+        Ok(proposals)
+    }
+
+    /// Get all proposals that expired before execution
+    async fn expired_proposals(&self, ctx: &Context<'_>) -> Result<Vec<ProposalView>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        let mut proposals = Vec::new();
+
+        let indices = state.expired_proposals.indices().await?;
+        for key in indices {
+            if let Some(proposal) = state.expired_proposals.get(&key).await? {
+                proposals.push(proposal_to_view(proposal, state));
+            }
+        }
+
+        Ok(proposals)
+    }
+
+    /// Get the cross-chain transfers that were rejected by their destination chain
+    async fn bounced_transfers(&self, ctx: &Context<'_>) -> Result<Vec<BouncedTransfer>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.bounced_transfers.read(..).await?)
+    }
+
+    /// Get the effective roles of an account (owners without explicit roles hold every role)
+    async fn roles(&self, ctx: &Context<'_>, account: Owner) -> Result<Vec<Role>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        match state.roles.get(&account).await? {
+            Some(roles) => Ok(roles),
+            None if state.owners.get().contains(&account) => Ok(Role::ALL.to_vec()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Get the allowance of a delegate
+    async fn allowance(&self, ctx: &Context<'_>, delegate: Owner) -> Result<Option<Allowance>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        Ok(state.allowances.get(&delegate).await?)
+    }
+
+    /// Get all allowances
+    async fn allowances(&self, ctx: &Context<'_>) -> Result<Vec<Allowance>> {
+        let state = ctx.data::<Arc<MultisigState>>()?;
+        let mut allowances = Vec::new();
+
+        let indices = state.allowances.indices().await?;
+        for key in indices {
+            if let Some(allowance) = state.allowances.get(&key).await? {
+                allowances.push(allowance);
+            }
+        }
+
+        Ok(allowances)
+    }
+
+    /// Get the account owner of the multisig application, which holds the treasury
+    async fn treasury_owner(&self, ctx: &Context<'_>) -> Result<Owner> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        Ok(treasury_owner(runtime))
+    }
+
+    /// Get the native balance held by the treasury
+    async fn treasury_balance(&self, ctx: &Context<'_>) -> Result<Amount> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        Ok(runtime.owner_balance(treasury_owner(runtime)))
+    }
+
+    /// Get the native balance of an account on the multisig chain
+    async fn account_balance(&self, ctx: &Context<'_>, owner: Owner) -> Result<Amount> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        Ok(runtime.owner_balance(owner))
+    }
+
+    /// Get the native balances of every account on the multisig chain
+    async fn account_balances(&self, ctx: &Context<'_>) -> Result<Vec<AccountBalance>> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        Ok(runtime
+            .owner_balances()
+            .into_iter()
+            .map(|(owner, balance)| AccountBalance { owner, balance })
+            .collect())
+    }
+
+    /// Get the fungible-token applications the multisig may transfer from
+    async fn allowed_tokens(&self, ctx: &Context<'_>) -> Result<Vec<ApplicationId>> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        Ok(runtime.application_parameters().allowed_tokens)
+    }
+
+    /// Get the balance of the multisig in a fungible-token application
+    async fn token_balance(&self, ctx: &Context<'_>, token: ApplicationId) -> Result<Amount> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        query_token_balance(runtime, token)
+    }
+
+    /// Get the balances of the multisig in every allowed fungible-token application
+    async fn token_balances(&self, ctx: &Context<'_>) -> Result<Vec<TokenBalance>> {
+        let runtime = ctx.data::<Arc<ServiceRuntime<MultisigService>>>()?;
+        runtime
+            .application_parameters()
+            .allowed_tokens
+            .into_iter()
+            .map(|token| {
+                Ok(TokenBalance {
+                    token,
+                    balance: query_token_balance(runtime, token)?,
+                })
+            })
+            .collect()
+    }
+
     /// Check if an owner has confirmed a proposal
     async fn has_confirmed(
         &self,
@@ -137,14 +400,16 @@ impl QueryRoot {
         Ok(confirmed_proposals.contains(&proposal_id))
     }
 
-    /// Get the number of confirmations for a proposal
+    /// Get the number of confirmations for a proposal (from current owners only)
     async fn confirmation_count(&self, ctx: &Context<'_>, proposal_id: u64) -> Result<u64> {
         let state = ctx.data::<Arc<MultisigState>>()?;
         
         if let Some(proposal) = state.pending_proposals.get(&proposal_id).await? {
-            Ok(proposal.confirmation_count)
+            Ok(proposal.confirmation_count(state.owners.get()))
         } else if let Some(proposal) = state.executed_proposals.get(&proposal_id).await? {
-            Ok(proposal.confirmation_count)
+            Ok(proposal.confirmation_count(state.owners.get()))
+        } else if let Some(proposal) = state.cancelled_proposals.get(&proposal_id).await? {
+            Ok(proposal.confirmation_count(state.owners.get()))
         } else {
             Ok(0)
         }
@@ -162,9 +427,9 @@ impl QueryRoot {
         let mut proposals = Vec::new();
         for id in confirmed_ids {
             if let Some(proposal) = state.pending_proposals.get(&id).await? {
-                proposals.push(proposal_to_view(proposal));
+                proposals.push(proposal_to_view(proposal, state));
             } else if let Some(proposal) = state.executed_proposals.get(&id).await? {
-                proposals.push(proposal_to_view(proposal));
+                proposals.push(proposal_to_view(proposal, state));
             }
         }
         
@@ -172,32 +437,172 @@ impl QueryRoot {
     }
 }
 
+/// Account owner of the multisig application, which holds the treasury
+fn treasury_owner(runtime: &ServiceRuntime<MultisigService>) -> AccountOwner {
+    AccountOwner::from(runtime.application_id().forget_abi())
+}
+
+/// Query a fungible-token application for the balance of the multisig application account
+fn query_token_balance(
+    runtime: &ServiceRuntime<MultisigService>,
+    token: ApplicationId,
+) -> Result<Amount> {
+    let owner = treasury_owner(runtime);
+    let request = Request::new(format!(
+        "query {{ accounts {{ entry(key: \"{}\") {{ value }} }} }}",
+        owner
+    ));
+    let response = runtime.query_application(token.with_abi::<FungibleTokenAbi>(), &request);
+    if let Some(error) = response.errors.first() {
+        return Err(error.message.clone().into());
+    }
+
+    // Accounts without an entry hold no tokens
+    let mut data = response.data.into_json()?;
+    let balance = data["accounts"]["entry"]["value"].take();
+    if balance.is_null() {
+        return Ok(Amount::ZERO);
+    }
+    Ok(serde_json::from_value(balance)?)
+}
+
 /// Convert internal Proposal to ProposalView for GraphQL
-fn proposal_to_view(proposal: Proposal) -> ProposalView {
-    let proposal_type_str = match &proposal.proposal_type {
+fn proposal_to_view(proposal: Proposal, state: &MultisigState) -> ProposalView {
+    let owners = state.owners.get();
+    let confirmation_weight = state.confirmation_weight(&proposal);
+    let required_threshold = state.required_threshold(&proposal.proposal_type);
+    ProposalView {
+        id: proposal.id,
+        proposal_type: proposal_type_to_string(&proposal.proposal_type),
+        proposer: proposal.proposer,
+        confirmation_count: proposal.confirmation_count(owners),
+        confirmation_weight,
+        required_threshold,
+        threshold_reached: confirmation_weight >= required_threshold,
+        required_time_delay: state.required_time_delay(&proposal.proposal_type),
+        rejection_count: proposal.rejection_count(owners),
+        rejection_weight: state.rejection_weight(&proposal),
+        veto_count: proposal.veto_count(owners),
+        vetoes: proposal.vetoes.clone(),
+        confirmers: proposal.confirmers,
+        executed: proposal.executed,
+        cancelled: proposal.cancelled,
+        created_at: proposal.created_at,
+        expires_at: proposal.expires_at,
+        executable_after: proposal.executable_after,
+        config_version: proposal.config_version,
+        digest: proposal.digest,
+        response: proposal.response.map(|response| format!("{:?}", response)),
+    }
+}
+
+/// Render a proposal type as a string for GraphQL
+fn proposal_type_to_string(proposal_type: &ProposalType) -> String {
+    match proposal_type {
+        ProposalType::LegacyTransfer { to, value, .. } => {
+            format!("LegacyTransfer {{ to: {:?}, value: {} }}", to, value)
+        }
         ProposalType::Transfer { to, value, .. } => {
             format!("Transfer {{ to: {:?}, value: {} }}", to, value)
         }
+        ProposalType::CrossChainTransfer { to, value, .. } => {
+            format!("CrossChainTransfer {{ to: {:?}, value: {} }}", to, value)
+        }
+        ProposalType::TokenTransfer { token, to, value } => {
+            format!(
+                "TokenTransfer {{ token: {}, to: {:?}, value: {} }}",
+                token, to, value
+            )
+        }
         ProposalType::AddOwner { owner } => {
             format!("AddOwner {{ owner: {:?} }}", owner)
         }
         ProposalType::RemoveOwner { owner } => {
             format!("RemoveOwner {{ owner: {:?} }}", owner)
         }
-        ProposalType::ReplaceOwner { old_owner, new_owner } => {
-            format!("ReplaceOwner {{ old_owner: {:?}, new_owner: {:?} }}", old_owner, new_owner)
+        ProposalType::ReplaceOwner {
+            old_owner,
+            new_owner,
+        } => {
+            format!(
+                "ReplaceOwner {{ old_owner: {:?}, new_owner: {:?} }}",
+                old_owner, new_owner
+            )
         }
         ProposalType::ChangeThreshold { threshold } => {
             format!("ChangeThreshold {{ threshold: {} }}", threshold)
         }
-    };
-
-    ProposalView {
-        id: proposal.id,
-        proposal_type: proposal_type_str,
-        proposer: proposal.proposer,
-        confirmation_count: proposal.confirmation_count,
-        executed: proposal.executed,
-        created_at: proposal.created_at,
+        ProposalType::CallApplication {
+            application_id,
+            operation_bytes,
+        } => {
+            format!(
+                "CallApplication {{ application_id: {}, operation_bytes: {} bytes }}",
+                application_id,
+                operation_bytes.len()
+            )
+        }
+        ProposalType::SetAllowance {
+            delegate,
+            amount,
+            period,
+        } => {
+            format!(
+                "SetAllowance {{ delegate: {:?}, amount: {}, period: {} }}",
+                delegate, amount, period
+            )
+        }
+        ProposalType::RemoveAllowance { delegate } => {
+            format!("RemoveAllowance {{ delegate: {:?} }}", delegate)
+        }
+        ProposalType::SetOwnerWeight { owner, weight } => {
+            format!(
+                "SetOwnerWeight {{ owner: {:?}, weight: {} }}",
+                owner, weight
+            )
+        }
+        ProposalType::SetKindThreshold { kind, threshold } => {
+            format!(
+                "SetKindThreshold {{ kind: {:?}, threshold: {:?} }}",
+                kind, threshold
+            )
+        }
+        ProposalType::SetGuard { guard } => format!("SetGuard {{ guard: {:?} }}", guard),
+        ProposalType::EnableModule { module } => format!("EnableModule {{ module: {} }}", module),
+        ProposalType::DisableModule { module } => {
+            format!("DisableModule {{ module: {} }}", module)
+        }
+        ProposalType::ChangeProposalLifetime { lifetime } => {
+            format!("ChangeProposalLifetime {{ lifetime: {} }}", lifetime)
+        }
+        ProposalType::ChangeTimeDelay { time_delay } => {
+            format!("ChangeTimeDelay {{ time_delay: {} }}", time_delay)
+        }
+        ProposalType::SetKindTimeDelay { kind, time_delay } => {
+            format!(
+                "SetKindTimeDelay {{ kind: {:?}, time_delay: {:?} }}",
+                kind, time_delay
+            )
+        }
+        ProposalType::SetVetoPolicy {
+            veto_threshold,
+            guardian,
+        } => format!(
+            "SetVetoPolicy {{ veto_threshold: {}, guardian: {:?} }}",
+            veto_threshold, guardian
+        ),
+        ProposalType::SetTransferTiers { tiers } => {
+            format!("SetTransferTiers {{ tiers: {} }}", tiers.len())
+        }
+        ProposalType::GrantRole { account, role } => {
+            format!("GrantRole {{ account: {:?}, role: {:?} }}", account, role)
+        }
+        ProposalType::RevokeRole { account, role } => {
+            format!("RevokeRole {{ account: {:?}, role: {:?} }}", account, role)
+        }
+        ProposalType::Batch(steps) => {
+            let steps: Vec<String> = steps.iter().map(proposal_type_to_string).collect();
+            format!("Batch {{ steps: [{}] }}", steps.join(", "))
+        }
     }
 }
@@ -3,11 +3,18 @@
 
 //! Multisig application state
 
-use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
-use linera_sdk::linera_base_types::AccountOwner;
+use std::collections::BTreeMap;
+
+use linera_sdk::linera_base_types::{AccountOwner, Amount, ApplicationId, CryptoHash};
+use linera_sdk::views::{
+    linera_views, LogView, MapView, RegisterView, RootView, ViewStorageContext,
+};
 
 // Re-export ProposalType from the main lib to avoid duplication
-pub use linera_multisig::ProposalType;
+pub use linera_multisig::{
+    Allowance, BouncedTransfer, MultisigResponse, PendingProposalPolicy, ProposalKind,
+    ProposalType, Role, TransferTier, Veto,
+};
 
 /// Multisig wallet state
 #[derive(RootView)]
@@ -15,20 +22,50 @@ pub use linera_multisig::ProposalType;
 pub struct MultisigState {
     /// Current owners of the multisig wallet
     pub owners: RegisterView<Vec<AccountOwner>>,
-    /// Number of confirmations required for execution
+    /// Voting weight per owner (owners without an entry have weight 1)
+    pub weights: RegisterView<BTreeMap<AccountOwner, u64>>,
+    /// Default confirmation weight required for execution
     pub threshold: RegisterView<u64>,
+    /// Thresholds overriding the default for specific kinds of proposals
+    pub kind_thresholds: RegisterView<BTreeMap<ProposalKind, u64>>,
+    /// Number of owner vetoes that cancel a proposal during its time-delay (0: owners cannot veto)
+    pub veto_threshold: RegisterView<u64>,
+    /// Account whose veto alone cancels a proposal during its time-delay
+    pub guardian: RegisterView<Option<AccountOwner>>,
+    /// Applications allowed to act through the multisig without per-action approval
+    pub enabled_modules: RegisterView<Vec<ApplicationId>>,
+    /// Guard application vetting every proposal execution, if any
+    pub guard: RegisterView<Option<ApplicationId>>,
+    /// Amount tiers for transfers, ordered by ascending `up_to`
+    pub transfer_tiers: RegisterView<Vec<TransferTier>>,
     /// Proposal nonce (unique ID for each proposal)
     pub nonce: RegisterView<u64>,
     /// Proposal lifetime in seconds (default: 7 days = 604800s)
     pub proposal_lifetime: RegisterView<u64>,
     /// Time-delay in seconds before execution (default: 0 = disabled, Safe native behavior)
     pub time_delay: RegisterView<u64>,
+    /// Time-delays overriding the default for specific kinds of proposals
+    pub kind_time_delays: RegisterView<BTreeMap<ProposalKind, u64>>,
+    /// Configuration version, incremented whenever the owners, their weights or a threshold change
+    pub config_version: RegisterView<u64>,
+    /// How pending proposals are handled after the owners or the threshold change
+    pub pending_proposal_policy: RegisterView<PendingProposalPolicy>,
     /// Pending proposals by ID
     pub pending_proposals: MapView<u64, Proposal>,
     /// Confirmations per owner: owner -> list of proposal IDs they've confirmed
     pub confirmations: MapView<AccountOwner, Vec<u64>>,
     /// Executed proposals (for historical record)
     pub executed_proposals: MapView<u64, Proposal>,
+    /// Cancelled proposals (by the proposer or because the threshold became unreachable)
+    pub cancelled_proposals: MapView<u64, Proposal>,
+    /// Proposals that expired before being executed
+    pub expired_proposals: MapView<u64, Proposal>,
+    /// Explicit roles per account; owners without an entry hold every role
+    pub roles: MapView<AccountOwner, Vec<Role>>,
+    /// Spending allowances by delegate
+    pub allowances: MapView<AccountOwner, Allowance>,
+    /// Cross-chain transfers rejected by their destination, one entry per rejected transfer
+    pub bounced_transfers: LogView<BouncedTransfer>,
 }
 
 /// A multisig proposal (can be transaction or governance operation)
@@ -38,16 +75,247 @@ pub struct Proposal {
     pub id: u64,
     /// Type of proposal
     pub proposal_type: ProposalType,
-    /// Owner who created the proposal
+    /// Owner who created the proposal (the module's account for module actions)
     pub proposer: AccountOwner,
-    /// Number of confirmations received
-    pub confirmation_count: u64,
+    /// Owners who confirmed the proposal
+    pub confirmers: Vec<AccountOwner>,
+    /// Owners who voted against the proposal
+    pub rejections: Vec<AccountOwner>,
+    /// Owners who revoked their confirmation, whose relayed signatures are no longer accepted
+    pub revoked_approvals: Vec<AccountOwner>,
+    /// Vetoes cast while the proposal waited out its time-delay
+    pub vetoes: Vec<Veto>,
     /// Whether the proposal has been executed
     pub executed: bool,
+    /// Whether the proposal has been cancelled
+    pub cancelled: bool,
     /// Timestamp when proposal was created (microseconds)
     pub created_at: u64,
     /// Expiration timestamp (microseconds) - Safe standard: 7+ days
     pub expires_at: u64,
-    /// Earliest execution timestamp (microseconds) - only used if time_delay > 0
+    /// Earliest execution timestamp (microseconds), stamped when the threshold is reached
     pub executable_after: u64,
+    /// Configuration version the proposal was created (or last re-evaluated) under
+    pub config_version: u64,
+    /// Canonical digest of the proposal content under its configuration version
+    pub digest: CryptoHash,
+    /// Response produced when the proposal was executed
+    pub response: Option<MultisigResponse>,
+}
+
+impl MultisigState {
+    /// Voting weight of an account (0 for non-owners)
+    pub fn weight_of(&self, account: &AccountOwner) -> u64 {
+        if !self.owners.get().contains(account) {
+            return 0;
+        }
+        self.weights.get().get(account).copied().unwrap_or(1)
+    }
+
+    /// Sum of the weights of all current owners (at most [`linera_multisig::MAX_TOTAL_WEIGHT`])
+    pub fn total_weight(&self) -> u64 {
+        self.owners
+            .get()
+            .iter()
+            .map(|owner| self.weight_of(owner))
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Threshold for a kind of proposal (the default threshold unless overridden)
+    pub fn kind_threshold(&self, kind: ProposalKind) -> u64 {
+        self.kind_thresholds
+            .get()
+            .get(&kind)
+            .copied()
+            .unwrap_or(*self.threshold.get())
+    }
+
+    /// Tier covering a transferred value, or the highest tier for values above every tier
+    ///
+    /// Returns `None` only if no tiers are configured.
+    pub fn transfer_tier(&self, value: Amount) -> Option<&TransferTier> {
+        let tiers = self.transfer_tiers.get();
+        tiers
+            .iter()
+            .find(|tier| tier.up_to.is_none_or(|up_to| value <= up_to))
+            .or_else(|| tiers.last())
+    }
+
+    /// Threshold required to execute a proposal
+    ///
+    /// Transfers use the tier covering their value, unless their kind is overridden with a
+    /// stricter threshold. Changing the threshold of a kind needs at least the old and the new
+    /// threshold of that kind, so that a kind's threshold cannot be changed with less weight
+    /// than the kind itself requires; likewise, replacing the tiers needs the highest current
+    /// tier threshold. Batches need the highest threshold of their steps and of the tier
+    /// covering their total value.
+    pub fn required_threshold(&self, proposal_type: &ProposalType) -> u64 {
+        let threshold = self.kind_threshold(proposal_type.kind());
+        match proposal_type {
+            ProposalType::LegacyTransfer { .. }
+            | ProposalType::Transfer { .. }
+            | ProposalType::CrossChainTransfer { .. } => {
+                match self.transfer_tier(proposal_type.transfer_value()) {
+                    Some(tier) => {
+                        let kind_override = self
+                            .kind_thresholds
+                            .get()
+                            .get(&proposal_type.kind())
+                            .copied()
+                            .unwrap_or(0);
+                        tier.threshold.max(kind_override)
+                    }
+                    None => threshold,
+                }
+            }
+            ProposalType::Batch(steps) => {
+                let value = proposal_type.transfer_value();
+                let tier_threshold = match self.transfer_tier(value) {
+                    Some(tier) if value > Amount::ZERO => tier.threshold,
+                    _ => 0,
+                };
+                steps
+                    .iter()
+                    .map(|step| self.required_threshold(step))
+                    .fold(threshold.max(tier_threshold), u64::max)
+            }
+            ProposalType::SetKindThreshold {
+                kind,
+                threshold: new_threshold,
+            } => threshold
+                .max(self.kind_threshold(*kind))
+                .max(new_threshold.unwrap_or(*self.threshold.get())),
+            ProposalType::SetTransferTiers { .. } => self
+                .transfer_tiers
+                .get()
+                .iter()
+                .map(|tier| tier.threshold)
+                .fold(threshold, u64::max),
+            _ => threshold,
+        }
+    }
+
+    /// Time-delay for a kind of proposal (the default time-delay unless overridden)
+    pub fn kind_time_delay(&self, kind: ProposalKind) -> u64 {
+        self.kind_time_delays
+            .get()
+            .get(&kind)
+            .copied()
+            .unwrap_or(*self.time_delay.get())
+    }
+
+    /// Time-delay in seconds required before executing a proposal
+    ///
+    /// Transfers use their tier's time-delay if it sets one. Changing a time-delay needs at
+    /// least the time-delay being changed, so that a change cannot shorten a timelock under a
+    /// shorter one: `ChangeTimeDelay` waits for the default time-delay, `SetKindTimeDelay` for
+    /// the current time-delay of its kind and `SetTransferTiers` for the longest tier
+    /// time-delay. Batches need the longest time-delay of their steps and of the tier covering
+    /// their total value.
+    pub fn required_time_delay(&self, proposal_type: &ProposalType) -> u64 {
+        let time_delay = self.kind_time_delay(proposal_type.kind());
+        let tier_delay = |value| {
+            self.transfer_tier(value)
+                .and_then(|tier| tier.time_delay)
+                .unwrap_or(time_delay)
+        };
+        match proposal_type {
+            ProposalType::LegacyTransfer { .. }
+            | ProposalType::Transfer { .. }
+            | ProposalType::CrossChainTransfer { .. } => tier_delay(proposal_type.transfer_value()),
+            ProposalType::Batch(steps) => {
+                let value = proposal_type.transfer_value();
+                let delay = if value > Amount::ZERO {
+                    tier_delay(value)
+                } else {
+                    time_delay
+                };
+                steps
+                    .iter()
+                    .map(|step| self.required_time_delay(step))
+                    .fold(delay, u64::max)
+            }
+            ProposalType::ChangeTimeDelay { .. } => time_delay.max(*self.time_delay.get()),
+            ProposalType::SetKindTimeDelay { kind, .. } => {
+                time_delay.max(self.kind_time_delay(*kind))
+            }
+            ProposalType::SetTransferTiers { .. } => self
+                .transfer_tiers
+                .get()
+                .iter()
+                .filter_map(|tier| tier.time_delay)
+                .fold(time_delay, u64::max),
+            _ => time_delay,
+        }
+    }
+
+    /// Longest configured time-delay, which must stay below the proposal lifetime
+    pub fn max_time_delay(&self) -> u64 {
+        let tier_delays = self
+            .transfer_tiers
+            .get()
+            .iter()
+            .filter_map(|tier| tier.time_delay);
+        self.kind_time_delays
+            .get()
+            .values()
+            .copied()
+            .chain(tier_delays)
+            .fold(*self.time_delay.get(), u64::max)
+    }
+
+    /// Highest threshold of any kind or tier, which the total owner weight must always reach
+    pub fn max_threshold(&self) -> u64 {
+        let tier_thresholds = self.transfer_tiers.get().iter().map(|tier| tier.threshold);
+        self.kind_thresholds
+            .get()
+            .values()
+            .copied()
+            .chain(tier_thresholds)
+            .fold(*self.threshold.get(), u64::max)
+    }
+
+    /// Weight of the confirmations from current owners
+    pub fn confirmation_weight(&self, proposal: &Proposal) -> u64 {
+        proposal
+            .confirmers
+            .iter()
+            .map(|confirmer| self.weight_of(confirmer))
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Weight of the rejections from current owners
+    pub fn rejection_weight(&self, proposal: &Proposal) -> u64 {
+        proposal
+            .rejections
+            .iter()
+            .map(|rejecter| self.weight_of(rejecter))
+            .fold(0, u64::saturating_add)
+    }
+}
+
+impl Proposal {
+    /// Number of confirmations from accounts that are still owners
+    pub fn confirmation_count(&self, owners: &[AccountOwner]) -> u64 {
+        self.confirmers
+            .iter()
+            .filter(|confirmer| owners.contains(confirmer))
+            .count() as u64
+    }
+
+    /// Number of rejections from accounts that are still owners
+    pub fn rejection_count(&self, owners: &[AccountOwner]) -> u64 {
+        self.rejections
+            .iter()
+            .filter(|rejecter| owners.contains(rejecter))
+            .count() as u64
+    }
+
+    /// Number of vetoes from accounts that are still owners
+    pub fn veto_count(&self, owners: &[AccountOwner]) -> u64 {
+        self.vetoes
+            .iter()
+            .filter(|veto| owners.contains(&veto.vetoer))
+            .count() as u64
+    }
 }
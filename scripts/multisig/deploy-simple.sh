@@ -46,6 +46,7 @@ linera publish-and-create \
   --storage "$STORAGE_CONFIG" \
   "$SCRIPT_DIR/../multisig-app/target/wasm32-unknown-unknown/release/multisig_contract.wasm" \
   "$SCRIPT_DIR/../multisig-app/target/wasm32-unknown-unknown/release/multisig_service.wasm" \
+  --json-parameters "{\"allowed_tokens\": []}" \
   --json-argument "{\"owners\": [\"User:0000000000000000000000000000000000000000000000000000000000000000\", \"User:0000000000000000000000000000000000000000000000000000000000000001\", \"User:0000000000000000000000000000000000000000000000000000000000000002\"], \"threshold\": 2, \"proposal_lifetime\": 604800, \"time_delay\": 0}" \
   2>&1 | tee "$WORK_DIR/deploy.log"
 